@@ -30,23 +30,23 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
-import { LoggedOutPlayerData } from "./logged_out_player_data_type";
+import { ChunkInterest } from "./chunk_interest_type";
 import { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
- * Table handle for the table `logged_out_player`.
+ * Table handle for the table `chunk_interest`.
  *
- * Obtain a handle from the [`loggedOutPlayer`] property on [`RemoteTables`],
- * like `ctx.db.loggedOutPlayer`.
+ * Obtain a handle from the [`chunkInterest`] property on [`RemoteTables`],
+ * like `ctx.db.chunkInterest`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.loggedOutPlayer.on_insert(...)`.
+ * like `ctx.db.chunkInterest.on_insert(...)`.
  */
-export class LoggedOutPlayerTableHandle {
-  tableCache: TableCache<LoggedOutPlayerData>;
+export class ChunkInterestTableHandle {
+  tableCache: TableCache<ChunkInterest>;
 
-  constructor(tableCache: TableCache<LoggedOutPlayerData>) {
+  constructor(tableCache: TableCache<ChunkInterest>) {
     this.tableCache = tableCache;
   }
 
@@ -54,53 +54,53 @@ export class LoggedOutPlayerTableHandle {
     return this.tableCache.count();
   }
 
-  iter(): Iterable<LoggedOutPlayerData> {
+  iter(): Iterable<ChunkInterest> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `identity` unique index on the table `logged_out_player`,
+   * Access to the `interestId` unique index on the table `chunk_interest`,
    * which allows point queries on the field of the same name
-   * via the [`LoggedOutPlayerIdentityUnique.find`] method.
+   * via the [`ChunkInterestInterestIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.loggedOutPlayer.identity().find(...)`.
+   * like `ctx.db.chunkInterest.interestId().find(...)`.
    *
-   * Get a handle on the `identity` unique index on the table `logged_out_player`.
+   * Get a handle on the `interestId` unique index on the table `chunk_interest`.
    */
-  identity = {
-    // Find the subscribed row whose `identity` column value is equal to `col_val`,
+  interestId = {
+    // Find the subscribed row whose `interestId` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: Identity): LoggedOutPlayerData | undefined => {
+    find: (col_val: bigint): ChunkInterest | undefined => {
       for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.identity, col_val)) {
+        if (deepEqual(row.interestId, col_val)) {
           return row;
         }
       }
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: LoggedOutPlayerData) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: ChunkInterest) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: LoggedOutPlayerData) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: ChunkInterest) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: LoggedOutPlayerData) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: ChunkInterest) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: LoggedOutPlayerData) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: ChunkInterest) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: LoggedOutPlayerData, newRow: LoggedOutPlayerData) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: ChunkInterest, newRow: ChunkInterest) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: LoggedOutPlayerData, newRow: LoggedOutPlayerData) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: ChunkInterest, newRow: ChunkInterest) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
@@ -58,22 +58,22 @@ export class GameTickScheduleTableHandle {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `id` unique index on the table `game_tick_schedule`,
+   * Access to the `scheduledId` unique index on the table `game_tick_schedule`,
    * which allows point queries on the field of the same name
-   * via the [`GameTickScheduleIdUnique.find`] method.
+   * via the [`GameTickScheduleScheduledIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.gameTickSchedule.id().find(...)`.
+   * like `ctx.db.gameTickSchedule.scheduledId().find(...)`.
    *
-   * Get a handle on the `id` unique index on the table `game_tick_schedule`.
+   * Get a handle on the `scheduledId` unique index on the table `game_tick_schedule`.
    */
-  id = {
-    // Find the subscribed row whose `id` column value is equal to `col_val`,
+  scheduledId = {
+    // Find the subscribed row whose `scheduledId` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: number): GameTickSchedule | undefined => {
+    find: (col_val: bigint): GameTickSchedule | undefined => {
       for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.id, col_val)) {
+        if (deepEqual(row.scheduledId, col_val)) {
           return row;
         }
       }
@@ -31,10 +31,8 @@ import {
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
 export type GameTickSchedule = {
-  id: number,
-  tickRate: number,
-  lastTick: Timestamp,
-  nextTick: Timestamp,
+  scheduledId: bigint,
+  scheduledAt: { tag: "Interval", value: TimeDuration } | { tag: "Time", value: Timestamp },
 };
 
 /**
@@ -47,10 +45,8 @@ export namespace GameTickSchedule {
   */
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
-      new ProductTypeElement("id", AlgebraicType.createU32Type()),
-      new ProductTypeElement("tickRate", AlgebraicType.createU32Type()),
-      new ProductTypeElement("lastTick", AlgebraicType.createTimestampType()),
-      new ProductTypeElement("nextTick", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("scheduledId", AlgebraicType.createU64Type()),
+      new ProductTypeElement("scheduledAt", AlgebraicType.createScheduleAtType()),
     ]);
   }
 
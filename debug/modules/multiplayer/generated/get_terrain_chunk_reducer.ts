@@ -30,9 +30,12 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+import { ChunkCoord as __ChunkCoord } from "./chunk_coord_type";
+import { ChunkEncoding as __ChunkEncoding } from "./chunk_encoding_type";
 
 export type GetTerrainChunk = {
-  chunkKey: string,
+  coord: __ChunkCoord,
+  encoding: __ChunkEncoding,
 };
 
 /**
@@ -45,7 +48,8 @@ export namespace GetTerrainChunk {
   */
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
-      new ProductTypeElement("chunkKey", AlgebraicType.createStringType()),
+      new ProductTypeElement("coord", __ChunkCoord.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("encoding", __ChunkEncoding.getTypeScriptAlgebraicType()),
     ]);
   }
 
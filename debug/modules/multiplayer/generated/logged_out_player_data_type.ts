@@ -30,13 +30,21 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+import { SurvivalStats as __SurvivalStats } from "./survival_stats_type";
+import { Vector3 as __Vector3 } from "./vector_3_type";
 export type LoggedOutPlayerData = {
   identity: Identity,
   username: string,
-  lastPositionX: number,
-  lastPositionY: number,
-  lastPositionZ: number,
-  logoutTime: Timestamp,
+  planetId: number | undefined,
+  viewRadius: number,
+  position: __Vector3,
+  rotation: __Vector3,
+  health: number,
+  maxHealth: number,
+  mana: number,
+  maxMana: number,
+  survival: __SurvivalStats,
+  lastSeen: Timestamp,
 };
 
 /**
@@ -51,10 +59,16 @@ export namespace LoggedOutPlayerData {
     return AlgebraicType.createProductType([
       new ProductTypeElement("identity", AlgebraicType.createIdentityType()),
       new ProductTypeElement("username", AlgebraicType.createStringType()),
-      new ProductTypeElement("lastPositionX", AlgebraicType.createF32Type()),
-      new ProductTypeElement("lastPositionY", AlgebraicType.createF32Type()),
-      new ProductTypeElement("lastPositionZ", AlgebraicType.createF32Type()),
-      new ProductTypeElement("logoutTime", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("planetId", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("viewRadius", AlgebraicType.createU32Type()),
+      new ProductTypeElement("position", __Vector3.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("rotation", __Vector3.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("health", AlgebraicType.createI32Type()),
+      new ProductTypeElement("maxHealth", AlgebraicType.createI32Type()),
+      new ProductTypeElement("mana", AlgebraicType.createI32Type()),
+      new ProductTypeElement("maxMana", AlgebraicType.createI32Type()),
+      new ProductTypeElement("survival", __SurvivalStats.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("lastSeen", AlgebraicType.createTimestampType()),
     ]);
   }
 
@@ -30,17 +30,29 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+import { InputState as __InputState } from "./input_state_type";
+import { SurvivalStats as __SurvivalStats } from "./survival_stats_type";
+import { Vector3 as __Vector3 } from "./vector_3_type";
 export type PlayerData = {
   identity: Identity,
   username: string,
-  positionX: number,
-  positionY: number,
-  positionZ: number,
-  rotationX: number,
-  rotationY: number,
-  rotationZ: number,
+  planetId: number | undefined,
+  chunkId: bigint,
+  viewRadius: number,
+  position: __Vector3,
+  rotation: __Vector3,
+  health: number,
+  maxHealth: number,
+  mana: number,
+  maxMana: number,
+  survival: __SurvivalStats,
+  verticalVelocity: number,
+  grounded: boolean,
+  isMoving: boolean,
+  isRunning: boolean,
+  lastInputSeq: number,
+  input: __InputState,
   lastUpdate: Timestamp,
-  isConnected: boolean,
 };
 
 /**
@@ -55,14 +67,23 @@ export namespace PlayerData {
     return AlgebraicType.createProductType([
       new ProductTypeElement("identity", AlgebraicType.createIdentityType()),
       new ProductTypeElement("username", AlgebraicType.createStringType()),
-      new ProductTypeElement("positionX", AlgebraicType.createF32Type()),
-      new ProductTypeElement("positionY", AlgebraicType.createF32Type()),
-      new ProductTypeElement("positionZ", AlgebraicType.createF32Type()),
-      new ProductTypeElement("rotationX", AlgebraicType.createF32Type()),
-      new ProductTypeElement("rotationY", AlgebraicType.createF32Type()),
-      new ProductTypeElement("rotationZ", AlgebraicType.createF32Type()),
+      new ProductTypeElement("planetId", AlgebraicType.createOptionType(AlgebraicType.createU32Type())),
+      new ProductTypeElement("chunkId", AlgebraicType.createU64Type()),
+      new ProductTypeElement("viewRadius", AlgebraicType.createU32Type()),
+      new ProductTypeElement("position", __Vector3.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("rotation", __Vector3.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("health", AlgebraicType.createI32Type()),
+      new ProductTypeElement("maxHealth", AlgebraicType.createI32Type()),
+      new ProductTypeElement("mana", AlgebraicType.createI32Type()),
+      new ProductTypeElement("maxMana", AlgebraicType.createI32Type()),
+      new ProductTypeElement("survival", __SurvivalStats.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("verticalVelocity", AlgebraicType.createF32Type()),
+      new ProductTypeElement("grounded", AlgebraicType.createBoolType()),
+      new ProductTypeElement("isMoving", AlgebraicType.createBoolType()),
+      new ProductTypeElement("isRunning", AlgebraicType.createBoolType()),
+      new ProductTypeElement("lastInputSeq", AlgebraicType.createU32Type()),
+      new ProductTypeElement("input", __InputState.getTypeScriptAlgebraicType()),
       new ProductTypeElement("lastUpdate", AlgebraicType.createTimestampType()),
-      new ProductTypeElement("isConnected", AlgebraicType.createBoolType()),
     ]);
   }
 
@@ -34,16 +34,16 @@ import { PlayerData } from "./player_data_type";
 import { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
- * Table handle for the table `player_data`.
+ * Table handle for the table `player`.
  *
- * Obtain a handle from the [`playerData`] property on [`RemoteTables`],
- * like `ctx.db.playerData`.
+ * Obtain a handle from the [`player`] property on [`RemoteTables`],
+ * like `ctx.db.player`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.playerData.on_insert(...)`.
+ * like `ctx.db.player.on_insert(...)`.
  */
-export class PlayerDataTableHandle {
+export class PlayerTableHandle {
   tableCache: TableCache<PlayerData>;
 
   constructor(tableCache: TableCache<PlayerData>) {
@@ -58,15 +58,15 @@ export class PlayerDataTableHandle {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `identity` unique index on the table `player_data`,
+   * Access to the `identity` unique index on the table `player`,
    * which allows point queries on the field of the same name
-   * via the [`PlayerDataIdentityUnique.find`] method.
+   * via the [`PlayerIdentityUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.playerData.identity().find(...)`.
+   * like `ctx.db.player.identity().find(...)`.
    *
-   * Get a handle on the `identity` unique index on the table `player_data`.
+   * Get a handle on the `identity` unique index on the table `player`.
    */
   identity = {
     // Find the subscribed row whose `identity` column value is equal to `col_val`,
@@ -79,6 +79,28 @@ export class PlayerDataTableHandle {
       }
     },
   };
+  /**
+   * Access to the `username` unique index on the table `player`,
+   * which allows point queries on the field of the same name
+   * via the [`PlayerUsernameUnique.find`] method.
+   *
+   * Users are encouraged not to explicitly reference this type,
+   * but to directly chain method calls,
+   * like `ctx.db.player.username().find(...)`.
+   *
+   * Get a handle on the `username` unique index on the table `player`.
+   */
+  username = {
+    // Find the subscribed row whose `username` column value is equal to `col_val`,
+    // if such a row is present in the client cache.
+    find: (col_val: string): PlayerData | undefined => {
+      for (let row of this.tableCache.iter()) {
+        if (deepEqual(row.username, col_val)) {
+          return row;
+        }
+      }
+    },
+  };
 
   onInsert = (cb: (ctx: EventContext, row: PlayerData) => void) => {
     return this.tableCache.onInsert(cb);
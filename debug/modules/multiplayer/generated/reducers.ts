@@ -2,7 +2,7 @@
 
 import { Identity } from '@clockworklabs/spacetimedb-sdk';
 import { DbConnection } from './connection';
-import { InputState } from './types';
+import { ChunkCoord, ChunkEncoding, InputState } from './types';
 
 // Reducer function types
 export type ReducerResult<T = void> = Promise<T>;
@@ -33,32 +33,31 @@ export async function updatePlayerInput(input: InputState): ReducerResult {
     return conn.call('update_player_input', [input]);
 }
 
-// Store terrain chunk reducer
+// Store terrain chunk reducer (admin only); materialData undefined keeps the chunk's materials
 export async function storeTerrainChunk(
-    chunkKey: string,
-    planetType: string,
-    x: number,
-    y: number,
-    z: number,
-    noiseData: number[]
+    coord: ChunkCoord,
+    baseVersion: bigint,
+    encoding: ChunkEncoding,
+    fieldData: Uint8Array,
+    materialData?: Uint8Array
 ): ReducerResult {
     const conn = getConnection();
-    return conn.call('store_terrain_chunk', [chunkKey, planetType, x, y, z, noiseData]);
+    return conn.call('store_terrain_chunk', [coord, baseVersion, encoding, fieldData, materialData]);
 }
 
-// Get terrain chunk reducer
-export async function getTerrainChunk(chunkKey: string): ReducerResult {
+// Get terrain chunk reducer; the field arrives in the requester's chunk_request row
+export async function getTerrainChunk(coord: ChunkCoord, encoding: ChunkEncoding): ReducerResult {
     const conn = getConnection();
-    return conn.call('get_terrain_chunk', [chunkKey]);
+    return conn.call('get_terrain_chunk', [coord, encoding]);
 }
 
 // Store initial chunks for planet reducer
 export async function storeInitialChunksForPlanet(
-    planetType: string,
+    planetId: number,
     radius: number
 ): ReducerResult {
     const conn = getConnection();
-    return conn.call('store_initial_chunks_for_planet', [planetType, radius]);
+    return conn.call('store_initial_chunks_for_planet', [planetId, radius]);
 }
 
 // Convenience function to create InputState
@@ -32,7 +32,7 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 
 export type StoreInitialChunksForPlanet = {
-  planetType: string,
+  planetId: number,
   radius: number,
 };
 
@@ -46,7 +46,7 @@ export namespace StoreInitialChunksForPlanet {
   */
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
-      new ProductTypeElement("planetType", AlgebraicType.createStringType()),
+      new ProductTypeElement("planetId", AlgebraicType.createU32Type()),
       new ProductTypeElement("radius", AlgebraicType.createI32Type()),
     ]);
   }
@@ -30,13 +30,15 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+import { ChunkCoord as __ChunkCoord } from "./chunk_coord_type";
+import { ChunkEncoding as __ChunkEncoding } from "./chunk_encoding_type";
 
 export type StoreTerrainChunk = {
-  chunkKey: string,
-  planetType: string,
-  x: number,
-  y: number,
-  z: number,
+  coord: __ChunkCoord,
+  baseVersion: bigint,
+  encoding: __ChunkEncoding,
+  fieldData: Uint8Array,
+  materialData: Uint8Array | undefined,
 };
 
 /**
@@ -49,11 +51,11 @@ export namespace StoreTerrainChunk {
   */
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
-      new ProductTypeElement("chunkKey", AlgebraicType.createStringType()),
-      new ProductTypeElement("planetType", AlgebraicType.createStringType()),
-      new ProductTypeElement("x", AlgebraicType.createI32Type()),
-      new ProductTypeElement("y", AlgebraicType.createI32Type()),
-      new ProductTypeElement("z", AlgebraicType.createI32Type()),
+      new ProductTypeElement("coord", __ChunkCoord.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("baseVersion", AlgebraicType.createU64Type()),
+      new ProductTypeElement("encoding", __ChunkEncoding.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("fieldData", AlgebraicType.createArrayType(AlgebraicType.createU8Type())),
+      new ProductTypeElement("materialData", AlgebraicType.createOptionType(AlgebraicType.createArrayType(AlgebraicType.createU8Type()))),
     ]);
   }
 
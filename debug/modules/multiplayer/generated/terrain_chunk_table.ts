@@ -58,22 +58,22 @@ export class TerrainChunkTableHandle {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `chunkKey` unique index on the table `terrain_chunk`,
+   * Access to the `chunkId` unique index on the table `terrain_chunk`,
    * which allows point queries on the field of the same name
-   * via the [`TerrainChunkChunkKeyUnique.find`] method.
+   * via the [`TerrainChunkChunkIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.terrainChunk.chunkKey().find(...)`.
+   * like `ctx.db.terrainChunk.chunkId().find(...)`.
    *
-   * Get a handle on the `chunkKey` unique index on the table `terrain_chunk`.
+   * Get a handle on the `chunkId` unique index on the table `terrain_chunk`.
    */
-  chunkKey = {
-    // Find the subscribed row whose `chunkKey` column value is equal to `col_val`,
+  chunkId = {
+    // Find the subscribed row whose `chunkId` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: string): TerrainChunk | undefined => {
+    find: (col_val: bigint): TerrainChunk | undefined => {
       for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.chunkKey, col_val)) {
+        if (deepEqual(row.chunkId, col_val)) {
           return row;
         }
       }
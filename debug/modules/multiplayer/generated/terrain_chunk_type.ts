@@ -30,13 +30,21 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+import { ChunkEncoding as __ChunkEncoding } from "./chunk_encoding_type";
 export type TerrainChunk = {
-  chunkKey: string,
-  planetType: string,
-  x: number,
-  y: number,
-  z: number,
+  chunkId: bigint,
+  planetId: number,
+  chunkX: number,
+  chunkY: number,
+  chunkZ: number,
+  deltaEncoding: __ChunkEncoding,
+  deltaData: Uint8Array,
+  modifiedVoxels: number,
+  materialData: Uint8Array,
+  modifiedMaterials: number,
+  version: bigint,
   createdAt: Timestamp,
+  lastAccessed: Timestamp,
 };
 
 /**
@@ -49,12 +57,19 @@ export namespace TerrainChunk {
   */
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
-      new ProductTypeElement("chunkKey", AlgebraicType.createStringType()),
-      new ProductTypeElement("planetType", AlgebraicType.createStringType()),
-      new ProductTypeElement("x", AlgebraicType.createI32Type()),
-      new ProductTypeElement("y", AlgebraicType.createI32Type()),
-      new ProductTypeElement("z", AlgebraicType.createI32Type()),
+      new ProductTypeElement("chunkId", AlgebraicType.createU64Type()),
+      new ProductTypeElement("planetId", AlgebraicType.createU32Type()),
+      new ProductTypeElement("chunkX", AlgebraicType.createI32Type()),
+      new ProductTypeElement("chunkY", AlgebraicType.createI32Type()),
+      new ProductTypeElement("chunkZ", AlgebraicType.createI32Type()),
+      new ProductTypeElement("deltaEncoding", __ChunkEncoding.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("deltaData", AlgebraicType.createArrayType(AlgebraicType.createU8Type())),
+      new ProductTypeElement("modifiedVoxels", AlgebraicType.createU32Type()),
+      new ProductTypeElement("materialData", AlgebraicType.createArrayType(AlgebraicType.createU8Type())),
+      new ProductTypeElement("modifiedMaterials", AlgebraicType.createU32Type()),
+      new ProductTypeElement("version", AlgebraicType.createU64Type()),
       new ProductTypeElement("createdAt", AlgebraicType.createTimestampType()),
+      new ProductTypeElement("lastAccessed", AlgebraicType.createTimestampType()),
     ]);
   }
 
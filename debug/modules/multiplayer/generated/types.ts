@@ -24,16 +24,43 @@ export interface InputState {
     sequence: number;
 }
 
+// SurvivalStats type from common.rs
+export interface SurvivalStats {
+    hunger: number;
+    thirst: number;
+    stamina: number;
+}
+
+// ChunkCoord type from common.rs
+export interface ChunkCoord {
+    planet_id: number;
+    x: number;
+    y: number;
+    z: number;
+}
+
+// ChunkEncoding type from common.rs
+export type ChunkEncoding =
+    | { tag: 'Raw' }
+    | { tag: 'Quantized16' }
+    | { tag: 'Quantized8' };
+
 // PlayerData table type from lib.rs
 export interface PlayerData {
     identity: Identity;
     username: string;
+    planet_id: number | undefined;
+    chunk_id: bigint;
+    view_radius: number;
     position: Vector3;
     rotation: Vector3;
     health: number;
     max_health: number;
     mana: number;
     max_mana: number;
+    survival: SurvivalStats;
+    vertical_velocity: number;
+    grounded: boolean;
     is_moving: boolean;
     is_running: boolean;
     last_input_seq: number;
@@ -45,12 +72,15 @@ export interface PlayerData {
 export interface LoggedOutPlayerData {
     identity: Identity;
     username: string;
+    planet_id: number | undefined;
+    view_radius: number;
     position: Vector3;
     rotation: Vector3;
     health: number;
     max_health: number;
     mana: number;
     max_mana: number;
+    survival: SurvivalStats;
     last_seen: Timestamp;
 }
 
@@ -62,16 +92,33 @@ export interface GameTickSchedule {
 
 // TerrainChunk table type from lib.rs
 export interface TerrainChunk {
-    chunk_key: string;
-    planet_type: string;
-    x: number;
-    y: number;
-    z: number;
-    noise_data: number[];
+    chunk_id: bigint;
+    planet_id: number;
+    chunk_x: number;
+    chunk_y: number;
+    chunk_z: number;
+    delta_encoding: ChunkEncoding;
+    delta_data: Uint8Array;
+    modified_voxels: number;
+    material_data: Uint8Array;
+    modified_materials: number;
+    version: bigint;
     created_at: Timestamp;
     last_accessed: Timestamp;
 }
 
+// ChunkRequest table type from lib.rs
+export interface ChunkRequest {
+    requester: Identity;
+    chunk_id: bigint;
+    coord: ChunkCoord;
+    version: bigint;
+    encoding: ChunkEncoding;
+    field_data: Uint8Array;
+    material_data: Uint8Array;
+    requested_at: Timestamp;
+}
+
 // Game constants from common.rs
 export const PLAYER_SPEED = 7.5;
 export const SPRINT_MULTIPLIER = 1.8;
@@ -30,41 +30,32 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+import { InputState as __InputState } from "./input_state_type";
 
-export type UpdatePlayerPosition = {
-  positionX: number,
-  positionY: number,
-  positionZ: number,
-  rotationX: number,
-  rotationY: number,
-  rotationZ: number,
+export type UpdatePlayerInput = {
+  input: __InputState,
 };
 
 /**
  * A namespace for generated helper functions.
  */
-export namespace UpdatePlayerPosition {
+export namespace UpdatePlayerInput {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   export function getTypeScriptAlgebraicType(): AlgebraicType {
     return AlgebraicType.createProductType([
-      new ProductTypeElement("positionX", AlgebraicType.createF32Type()),
-      new ProductTypeElement("positionY", AlgebraicType.createF32Type()),
-      new ProductTypeElement("positionZ", AlgebraicType.createF32Type()),
-      new ProductTypeElement("rotationX", AlgebraicType.createF32Type()),
-      new ProductTypeElement("rotationY", AlgebraicType.createF32Type()),
-      new ProductTypeElement("rotationZ", AlgebraicType.createF32Type()),
+      new ProductTypeElement("input", __InputState.getTypeScriptAlgebraicType()),
     ]);
   }
 
-  export function serialize(writer: BinaryWriter, value: UpdatePlayerPosition): void {
-    UpdatePlayerPosition.getTypeScriptAlgebraicType().serialize(writer, value);
+  export function serialize(writer: BinaryWriter, value: UpdatePlayerInput): void {
+    UpdatePlayerInput.getTypeScriptAlgebraicType().serialize(writer, value);
   }
 
-  export function deserialize(reader: BinaryReader): UpdatePlayerPosition {
-    return UpdatePlayerPosition.getTypeScriptAlgebraicType().deserialize(reader);
+  export function deserialize(reader: BinaryReader): UpdatePlayerInput {
+    return UpdatePlayerInput.getTypeScriptAlgebraicType().deserialize(reader);
   }
 
 }
@@ -0,0 +1,119 @@
+/*
+ * Infinia Multiplayer - building.rs
+ *
+ * This file contains the geometry used to validate structure placement.
+ * Prefab sizes mirror the blueprint geometries in
+ * debug/modules/wilderness/buildingSystem.ts.
+ *
+ * Key components:
+ * - prefab_size / prefab_max_health: Dimensions and durability of each prefab
+ * - Bounds: Axis-aligned box with overlap tests
+ * - structure_bounds: World box of a placed prefab
+ * - player_bounds: World box occupied by a player
+ *
+ * Placement rules (enforced by place_structure in lib.rs):
+ * - Structures are upright; only the yaw (rotation.y) turns their box
+ * - Structures may touch but not overlap other structures or players
+ * - The box, shrunk by STRUCTURE_TERRAIN_TOLERANCE, must not contain solid terrain
+ * - A structure rests on solid terrain or touches another structure
+ *
+ * When modifying:
+ * - Keep prefab sizes in sync with the client blueprint geometries
+ */
+
+use crate::common::{Vector3, PrefabType, PLAYER_RADIUS, PLAYER_HEIGHT};
+
+// --- Prefabs ---
+
+// Full box dimensions (width, height, depth) before rotation
+pub fn prefab_size(prefab: PrefabType) -> Vector3 {
+    match prefab {
+        PrefabType::Foundation => Vector3 { x: 2.0, y: 0.2, z: 2.0 },
+        PrefabType::Wall => Vector3 { x: 2.0, y: 3.0, z: 0.2 },
+        PrefabType::Roof => Vector3 { x: 2.0, y: 0.2, z: 2.0 },
+        PrefabType::Window => Vector3 { x: 0.8, y: 0.8, z: 0.1 },
+        PrefabType::Door => Vector3 { x: 1.0, y: 2.0, z: 0.1 },
+    }
+}
+
+pub fn prefab_max_health(prefab: PrefabType) -> u32 {
+    match prefab {
+        PrefabType::Foundation => 500,
+        PrefabType::Wall => 300,
+        PrefabType::Roof => 200,
+        PrefabType::Window => 50,
+        PrefabType::Door => 100,
+    }
+}
+
+// --- Bounds ---
+
+#[derive(Clone, Debug)]
+pub struct Bounds {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Bounds {
+    // True if the boxes intersect once grown by margin; a negative margin lets them touch
+    pub fn overlaps(&self, other: &Bounds, margin: f32) -> bool {
+        self.min.x < other.max.x + margin && other.min.x < self.max.x + margin
+            && self.min.y < other.max.y + margin && other.min.y < self.max.y + margin
+            && self.min.z < other.max.z + margin && other.min.z < self.max.z + margin
+    }
+
+    // Box shrunk by amount on every side, never past its center
+    pub fn shrink(&self, amount: f32) -> Bounds {
+        let axis = |min: f32, max: f32| {
+            let amount = amount.min((max - min) / 2.0);
+            (min + amount, max - amount)
+        };
+        let (min_x, max_x) = axis(self.min.x, self.max.x);
+        let (min_y, max_y) = axis(self.min.y, self.max.y);
+        let (min_z, max_z) = axis(self.min.z, self.max.z);
+        Bounds {
+            min: Vector3 { x: min_x, y: min_y, z: min_z },
+            max: Vector3 { x: max_x, y: max_y, z: max_z },
+        }
+    }
+
+    // Points spaced at most one unit apart covering the box, including its faces
+    pub fn sample_points(&self) -> Vec<Vector3> {
+        let axis = |min: f32, max: f32| {
+            let steps = (max - min).ceil() as usize;
+            (0..=steps).map(move |step| if steps == 0 { min } else { min + (max - min) * step as f32 / steps as f32 })
+        };
+        let mut points = Vec::new();
+        for y in axis(self.min.y, self.max.y) {
+            for z in axis(self.min.z, self.max.z) {
+                for x in axis(self.min.x, self.max.x) {
+                    points.push(Vector3 { x, y, z });
+                }
+            }
+        }
+        points
+    }
+}
+
+// Boxes are centred on the structure position and turned by its yaw
+pub fn structure_bounds(prefab: PrefabType, position: &Vector3, rotation: &Vector3) -> Bounds {
+    let size = prefab_size(prefab);
+    let (sin, cos) = rotation.y.sin_cos();
+    let half = Vector3 {
+        x: (cos.abs() * size.x + sin.abs() * size.z) / 2.0,
+        y: size.y / 2.0,
+        z: (sin.abs() * size.x + cos.abs() * size.z) / 2.0,
+    };
+    Bounds {
+        min: Vector3 { x: position.x - half.x, y: position.y - half.y, z: position.z - half.z },
+        max: Vector3 { x: position.x + half.x, y: position.y + half.y, z: position.z + half.z },
+    }
+}
+
+// Player positions are at the feet
+pub fn player_bounds(position: &Vector3) -> Bounds {
+    Bounds {
+        min: Vector3 { x: position.x - PLAYER_RADIUS, y: position.y, z: position.z - PLAYER_RADIUS },
+        max: Vector3 { x: position.x + PLAYER_RADIUS, y: position.y + PLAYER_HEIGHT, z: position.z + PLAYER_RADIUS },
+    }
+}
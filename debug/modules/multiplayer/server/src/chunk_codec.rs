@@ -0,0 +1,488 @@
+/*
+ * Infinia Multiplayer - chunk_codec.rs
+ *
+ * This file contains the binary encodings used for terrain payloads: full
+ * density fields (materialized chunks, chunk requests, uploads) and the sparse
+ * voxel deltas stored in terrain_chunk.
+ *
+ * Key functions:
+ * - encode_field / decode_field: Full CHUNK_VOXEL_COUNT density fields
+ * - encode_deltas / decode_deltas: Sparse index/value delta lists
+ * - encode_materials / decode_materials: Full material-id fields (run-length)
+ * - encode_material_deltas / decode_material_deltas: Sparse material-id deltas
+ * - encoding_precision: Largest step between two decodable densities
+ * - encoded_value: The density a value decodes to after a round trip through an encoding
+ *
+ * Formats (selected by the ChunkEncoding tag stored next to each payload):
+ * - Raw: little-endian f32 per value, lossless
+ * - Quantized16: densities in [-DENSITY_RANGE, DENSITY_RANGE] mapped to u16
+ * - Quantized8: densities in [-DENSITY_RANGE, DENSITY_RANGE] mapped to i8
+ *
+ * Quantized fields are stored as differences between consecutive samples,
+ * zigzag varint encoded with run-length compression of repeated differences:
+ *   token = varint((zigzag(diff) << 1) | has_run) [varint(extra_repeats) if has_run]
+ * Sparse deltas are varint(count) then, per entry, varint(index gap) and the value.
+ * Material ids are u8 palette indices and always lossless: full fields are
+ * (id, varint(run_length)) pairs, sparse deltas use the sparse layout with u8 values.
+ *
+ * When modifying:
+ * - Payloads are persisted and decoded by clients; never change an existing
+ *   format, add a new ChunkEncoding variant instead
+ */
+
+use crate::common::ChunkEncoding;
+use crate::terrain::{VoxelDeltas, MaterialDeltas};
+
+// Densities outside this range are clamped by the quantized encodings
+pub const DENSITY_RANGE: f32 = 32.0;
+
+pub fn encoding_precision(encoding: ChunkEncoding) -> f32 {
+    match encoding {
+        ChunkEncoding::Raw => 0.0,
+        ChunkEncoding::Quantized16 => 2.0 * DENSITY_RANGE / u16::MAX as f32,
+        ChunkEncoding::Quantized8 => DENSITY_RANGE / i8::MAX as f32,
+    }
+}
+
+// Clamped and quantized like encode_field; compare uploads against bases passed through this
+pub fn encoded_value(value: f32, encoding: ChunkEncoding) -> f32 {
+    match encoding {
+        ChunkEncoding::Raw => value,
+        _ => dequantize(quantize(value, encoding), encoding),
+    }
+}
+
+// --- Quantization ---
+
+fn quantize(value: f32, encoding: ChunkEncoding) -> i64 {
+    let value = value.clamp(-DENSITY_RANGE, DENSITY_RANGE);
+    match encoding {
+        ChunkEncoding::Raw => unreachable!("raw values are not quantized"),
+        ChunkEncoding::Quantized16 => ((value + DENSITY_RANGE) / encoding_precision(encoding)).round() as i64,
+        ChunkEncoding::Quantized8 => (value / encoding_precision(encoding)).round() as i64,
+    }
+}
+
+fn dequantize(value: i64, encoding: ChunkEncoding) -> f32 {
+    match encoding {
+        ChunkEncoding::Raw => unreachable!("raw values are not quantized"),
+        ChunkEncoding::Quantized16 => value as f32 * encoding_precision(encoding) - DENSITY_RANGE,
+        ChunkEncoding::Quantized8 => value as f32 * encoding_precision(encoding),
+    }
+}
+
+// --- Byte Primitives ---
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    fn take(&mut self, count: usize) -> Result<&'a [u8], String> {
+        let end = self.position + count;
+        let slice = self.bytes
+            .get(self.position..end)
+            .ok_or_else(|| format!("Chunk payload truncated at byte {}", self.position))?;
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(format!("Varint too long at byte {}", self.position))
+    }
+}
+
+fn write_value(out: &mut Vec<u8>, value: f32, encoding: ChunkEncoding) {
+    match encoding {
+        ChunkEncoding::Raw => out.extend_from_slice(&value.to_le_bytes()),
+        ChunkEncoding::Quantized16 => out.extend_from_slice(&(quantize(value, encoding) as u16).to_le_bytes()),
+        ChunkEncoding::Quantized8 => out.push(quantize(value, encoding) as i8 as u8),
+    }
+}
+
+fn read_value(reader: &mut Reader, encoding: ChunkEncoding) -> Result<f32, String> {
+    Ok(match encoding {
+        ChunkEncoding::Raw => {
+            let value = f32::from_le_bytes(reader.take(4)?.try_into().unwrap());
+            if !value.is_finite() {
+                return Err(format!("Non-finite sample {} at byte {}", value, reader.position - 4));
+            }
+            value
+        }
+        ChunkEncoding::Quantized16 => {
+            dequantize(u16::from_le_bytes(reader.take(2)?.try_into().unwrap()) as i64, encoding)
+        }
+        ChunkEncoding::Quantized8 => dequantize(reader.take(1)?[0] as i8 as i64, encoding),
+    })
+}
+
+// --- Full Fields ---
+
+pub fn encode_field(values: &[f32], encoding: ChunkEncoding) -> Vec<u8> {
+    let mut out = Vec::new();
+    if encoding == ChunkEncoding::Raw {
+        for &value in values {
+            write_value(&mut out, value, encoding);
+        }
+        return out;
+    }
+
+    let quantized: Vec<i64> = values.iter().map(|&value| quantize(value, encoding)).collect();
+    let mut previous = 0;
+    let mut index = 0;
+    while index < quantized.len() {
+        let diff = quantized[index] - previous;
+        let mut run = 1;
+        while index + run < quantized.len() && quantized[index + run] - quantized[index + run - 1] == diff {
+            run += 1;
+        }
+
+        write_varint(&mut out, zigzag(diff) << 1 | (run > 1) as u64);
+        if run > 1 {
+            write_varint(&mut out, (run - 1) as u64);
+        }
+
+        previous = quantized[index + run - 1];
+        index += run;
+    }
+    out
+}
+
+pub fn decode_field(bytes: &[u8], encoding: ChunkEncoding, expected_len: usize) -> Result<Vec<f32>, String> {
+    let mut reader = Reader::new(bytes);
+    let mut values = Vec::with_capacity(expected_len);
+
+    if encoding == ChunkEncoding::Raw {
+        while !reader.is_empty() && values.len() < expected_len {
+            values.push(read_value(&mut reader, encoding)?);
+        }
+    } else {
+        let mut previous: i64 = 0;
+        while !reader.is_empty() && values.len() < expected_len {
+            let token = reader.varint()?;
+            let diff = unzigzag(token >> 1);
+            let run = if token & 1 == 1 {
+                reader.varint()?
+                    .checked_add(1)
+                    .ok_or_else(|| format!("Run length overflows at byte {}", reader.position))?
+            } else {
+                1
+            };
+            if run > (expected_len - values.len()) as u64 {
+                return Err(format!(
+                    "Run of {} samples at byte {} exceeds the {} expected",
+                    run, reader.position, expected_len
+                ));
+            }
+            for _ in 0..run {
+                previous = previous
+                    .checked_add(diff)
+                    .ok_or_else(|| format!("Quantized sample {} overflows", values.len()))?;
+                values.push(dequantize(previous, encoding));
+            }
+        }
+    }
+
+    if values.len() != expected_len || !reader.is_empty() {
+        return Err(format!(
+            "{:?} chunk payload decodes to {} samples, expected {}",
+            encoding,
+            values.len(),
+            expected_len
+        ));
+    }
+    Ok(values)
+}
+
+// --- Sparse Deltas ---
+
+pub fn encode_deltas(deltas: &VoxelDeltas, encoding: ChunkEncoding) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_varint(&mut out, deltas.len() as u64);
+
+    let mut previous = 0;
+    for (&index, &value) in deltas {
+        write_varint(&mut out, (index - previous) as u64);
+        write_value(&mut out, value, encoding);
+        previous = index;
+    }
+    out
+}
+
+pub fn decode_deltas(bytes: &[u8], encoding: ChunkEncoding) -> Result<VoxelDeltas, String> {
+    let mut reader = Reader::new(bytes);
+    let mut deltas = VoxelDeltas::new();
+    if reader.is_empty() {
+        return Ok(deltas);
+    }
+
+    let count = reader.varint()?;
+    let mut index = 0u64;
+    for _ in 0..count {
+        index = index
+            .checked_add(reader.varint()?)
+            .ok_or_else(|| format!("Voxel index overflows after {}", index))?;
+        let index = u32::try_from(index).map_err(|_| format!("Voxel index {} out of range", index))?;
+        deltas.insert(index, read_value(&mut reader, encoding)?);
+    }
+
+    if !reader.is_empty() {
+        return Err(format!("Trailing bytes after {} voxel deltas", count));
+    }
+    Ok(deltas)
+}
+
+// --- Materials ---
+
+pub fn encode_materials(materials: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut index = 0;
+    while index < materials.len() {
+        let material = materials[index];
+        let run = materials[index..].iter().take_while(|&&m| m == material).count();
+        out.push(material);
+        write_varint(&mut out, run as u64);
+        index += run;
+    }
+    out
+}
+
+pub fn decode_materials(bytes: &[u8], expected_len: usize) -> Result<Vec<u8>, String> {
+    let mut reader = Reader::new(bytes);
+    let mut materials = Vec::with_capacity(expected_len);
+    while !reader.is_empty() {
+        let material = reader.take(1)?[0];
+        let run = reader.varint()?;
+        if (materials.len() as u64).checked_add(run).is_none_or(|end| end > expected_len as u64) {
+            return Err(format!("Material payload exceeds {} samples", expected_len));
+        }
+        materials.extend(std::iter::repeat_n(material, run as usize));
+    }
+
+    if materials.len() != expected_len {
+        return Err(format!("Material payload decodes to {} samples, expected {}", materials.len(), expected_len));
+    }
+    Ok(materials)
+}
+
+pub fn encode_material_deltas(deltas: &MaterialDeltas) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_varint(&mut out, deltas.len() as u64);
+
+    let mut previous = 0;
+    for (&index, &material) in deltas {
+        write_varint(&mut out, (index - previous) as u64);
+        out.push(material);
+        previous = index;
+    }
+    out
+}
+
+pub fn decode_material_deltas(bytes: &[u8]) -> Result<MaterialDeltas, String> {
+    let mut reader = Reader::new(bytes);
+    let mut deltas = MaterialDeltas::new();
+    if reader.is_empty() {
+        return Ok(deltas);
+    }
+
+    let count = reader.varint()?;
+    let mut index = 0u64;
+    for _ in 0..count {
+        index = index
+            .checked_add(reader.varint()?)
+            .ok_or_else(|| format!("Voxel index overflows after {}", index))?;
+        let index = u32::try_from(index).map_err(|_| format!("Voxel index {} out of range", index))?;
+        deltas.insert(index, reader.take(1)?[0]);
+    }
+
+    if !reader.is_empty() {
+        return Err(format!("Trailing bytes after {} material deltas", count));
+    }
+    Ok(deltas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENCODINGS: [ChunkEncoding; 3] = [ChunkEncoding::Raw, ChunkEncoding::Quantized16, ChunkEncoding::Quantized8];
+
+    // Smooth ramps with flat runs, like real density fields, plus both range limits
+    fn sample_field() -> Vec<f32> {
+        let mut field: Vec<f32> = (0..300).map(|i| (i as f32 * 0.1).sin() * 20.0).collect();
+        field.extend(std::iter::repeat_n(-DENSITY_RANGE, 40));
+        field.extend((0..40).map(|i| i as f32 * 0.5 - 10.0));
+        field.push(DENSITY_RANGE);
+        field
+    }
+
+    fn assert_close(expected: f32, actual: f32, encoding: ChunkEncoding) {
+        let tolerance = encoding_precision(encoding) / 2.0 + 1e-4;
+        assert!((expected - actual).abs() <= tolerance, "{:?}: {} decoded as {}", encoding, expected, actual);
+    }
+
+    #[test]
+    fn test_field_round_trip() {
+        let field = sample_field();
+        for encoding in ENCODINGS {
+            let decoded = decode_field(&encode_field(&field, encoding), encoding, field.len()).unwrap();
+            assert_eq!(decoded.len(), field.len());
+            for (&expected, &actual) in field.iter().zip(decoded.iter()) {
+                assert_close(expected, actual, encoding);
+            }
+        }
+        let raw = decode_field(&encode_field(&field, ChunkEncoding::Raw), ChunkEncoding::Raw, field.len()).unwrap();
+        assert_eq!(raw, field);
+    }
+
+    #[test]
+    fn test_quantized_field_clamps_to_range() {
+        for encoding in [ChunkEncoding::Quantized16, ChunkEncoding::Quantized8] {
+            let decoded = decode_field(&encode_field(&[-100.0, 100.0], encoding), encoding, 2).unwrap();
+            assert_close(-DENSITY_RANGE, decoded[0], encoding);
+            assert_close(DENSITY_RANGE, decoded[1], encoding);
+        }
+    }
+
+    #[test]
+    fn test_encoded_value_matches_decoded_field() {
+        let field = vec![-100.0, -32.5, -1.234, 0.0, 7.77, 31.99, 45.0];
+        for encoding in ENCODINGS {
+            let decoded = decode_field(&encode_field(&field, encoding), encoding, field.len()).unwrap();
+            let expected: Vec<f32> = field.iter().map(|&value| encoded_value(value, encoding)).collect();
+            assert_eq!(decoded, expected);
+        }
+    }
+
+    #[test]
+    fn test_deltas_round_trip() {
+        let deltas: VoxelDeltas = [(0, -3.25), (1, 0.5), (700, 12.0), (35936, -31.0)].into_iter().collect();
+        for encoding in ENCODINGS {
+            let decoded = decode_deltas(&encode_deltas(&deltas, encoding), encoding).unwrap();
+            assert_eq!(decoded.keys().collect::<Vec<_>>(), deltas.keys().collect::<Vec<_>>());
+            for (index, &expected) in &deltas {
+                assert_close(expected, decoded[index], encoding);
+            }
+        }
+        assert!(decode_deltas(&[], ChunkEncoding::Raw).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_materials_round_trip() {
+        let materials: Vec<u8> = (0..500).map(|i| (i / 37 % 4) as u8).collect();
+        assert_eq!(decode_materials(&encode_materials(&materials), materials.len()).unwrap(), materials);
+
+        let deltas: MaterialDeltas = [(3, 1), (4, 0), (35936, 7)].into_iter().collect();
+        assert_eq!(decode_material_deltas(&encode_material_deltas(&deltas)).unwrap(), deltas);
+    }
+
+    #[test]
+    fn test_truncated_payloads_are_rejected() {
+        let field = sample_field();
+        for encoding in ENCODINGS {
+            let bytes = encode_field(&field, encoding);
+            assert!(decode_field(&bytes[..bytes.len() - 1], encoding, field.len()).is_err());
+            assert!(decode_field(&bytes, encoding, field.len() + 1).is_err());
+
+            let deltas: VoxelDeltas = [(5, 1.0), (9, -2.0)].into_iter().collect();
+            let bytes = encode_deltas(&deltas, encoding);
+            assert!(decode_deltas(&bytes[..bytes.len() - 1], encoding).is_err());
+        }
+
+        let materials = encode_materials(&[1, 1, 2, 3]);
+        assert!(decode_materials(&materials[..materials.len() - 1], 4).is_err());
+        let deltas = encode_material_deltas(&[(2, 5)].into_iter().collect());
+        assert!(decode_material_deltas(&deltas[..deltas.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_garbage_payloads_are_rejected() {
+        // Run length of u64::MAX extra repeats
+        let mut run_overflow = Vec::new();
+        write_varint(&mut run_overflow, 1);
+        write_varint(&mut run_overflow, u64::MAX);
+        assert!(decode_field(&run_overflow, ChunkEncoding::Quantized16, 8).is_err());
+
+        // A run of u64::MAX samples after the first one must not wrap the length check
+        let mut run_wrap = Vec::new();
+        write_varint(&mut run_wrap, 0);
+        write_varint(&mut run_wrap, 1);
+        write_varint(&mut run_wrap, u64::MAX - 1);
+        assert!(decode_field(&run_wrap, ChunkEncoding::Quantized16, 8).is_err());
+
+        // A run longer than the remaining samples
+        let mut long_run = Vec::new();
+        write_varint(&mut long_run, 1);
+        write_varint(&mut long_run, 8);
+        assert!(decode_field(&long_run, ChunkEncoding::Quantized8, 8).is_err());
+
+        // Raw samples must be finite
+        for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let bytes: Vec<u8> = [1.0, value].iter().flat_map(|v: &f32| v.to_le_bytes()).collect();
+            assert!(decode_field(&bytes, ChunkEncoding::Raw, 2).is_err());
+        }
+        let mut nan_delta = Vec::new();
+        write_varint(&mut nan_delta, 1);
+        write_varint(&mut nan_delta, 0);
+        nan_delta.extend_from_slice(&f32::NAN.to_le_bytes());
+        assert!(decode_deltas(&nan_delta, ChunkEncoding::Raw).is_err());
+
+        // The largest encodable difference repeated until the running sample overflows
+        let mut sample_overflow = Vec::new();
+        write_varint(&mut sample_overflow, zigzag(i64::MAX >> 1) << 1 | 1);
+        write_varint(&mut sample_overflow, 2);
+        assert!(decode_field(&sample_overflow, ChunkEncoding::Quantized16, 3).is_err());
+
+        // A second index gap that overflows the running voxel index
+        let mut index_overflow = Vec::new();
+        write_varint(&mut index_overflow, 2);
+        write_varint(&mut index_overflow, 1);
+        index_overflow.push(0);
+        write_varint(&mut index_overflow, u64::MAX);
+        index_overflow.push(0);
+        assert!(decode_deltas(&index_overflow, ChunkEncoding::Quantized8).is_err());
+        assert!(decode_material_deltas(&index_overflow).is_err());
+
+        // Material runs past the field, varints that never terminate and trailing bytes
+        let mut material_overflow = vec![1];
+        write_varint(&mut material_overflow, u64::MAX);
+        assert!(decode_materials(&material_overflow, 4).is_err());
+        assert!(decode_field(&[0xff; 11], ChunkEncoding::Quantized8, 4).is_err());
+        let mut trailing = encode_material_deltas(&[(2, 5)].into_iter().collect());
+        trailing.push(0);
+        assert!(decode_material_deltas(&trailing).is_err());
+    }
+}
@@ -6,11 +6,36 @@
  * Key components:
  * - Vector3: 3D vector struct for positions, rotations and movement
  * - InputState: Player input tracking with all possible input actions
- * - Game constants: Speed values that affect player movement
+ * - BrushShape / EditMode: Terrain edit brush parameters
+ * - NoiseParams / PlanetMaterial: Planet generation parameters and material palette
+ * - ChunkCoord: Typed chunk key (planet + chunk coordinates) and its packed u64 id
+ * - ChunkEncoding: Format tag for binary terrain payloads (see chunk_codec.rs)
+ * - ToolType: Mining tools (stats in mining.rs)
+ * - ItemStack: Item id and quantity used by recipes
+ * - PrefabType: Placeable structure types (geometry in building.rs)
+ * - SurvivalStats: Player hunger, thirst and stamina (rules in survival.rs)
+ * - PlanetBounds: Region of a planet players may occupy
+ * - MovementMode: Whether "up" is world Y or away from the planet center
+ * - Game constants: Tick rate, speed values, player dimensions, vertical physics and collision
+ * - Terrain constants: Chunk dimensions, brush limits, view radius and storage limits
+ * - Mining constants: Reach and carve size of mining actions
+ * - Inventory constants: Default capacity and slot count of player inventories
+ * - Crafting constants: Craft queue and recipe limits
+ * - Building constants: Reach and tolerances of structure placement
+ * - World clock constants: Day length and time scale limits
+ * - Survival constants: Decay, drain and damage rates of survival stats
  * 
  * These structures are used by:
  * - lib.rs: For database table definitions
  * - player_logic.rs: For movement calculations and state updates
+ * - terrain.rs: For chunk indexing, voxel deltas and brush application
+ * - noise.rs: For procedural terrain generation parameters
+ * - chunk_codec.rs: For encoding terrain payloads
+ * - mining.rs: For tool and material mining rules
+ * - crafting.rs: For default recipes
+ * - building.rs: For structure placement geometry
+ * - day_night.rs: For advancing planet day/night clocks
+ * - survival.rs: For survival stat updates
  * 
  * When modifying:
  * - Changes to Vector3 or InputState will affect database schema
@@ -47,8 +72,251 @@ pub struct InputState {
     pub sequence: u32,
 }
 
+// Shape of the brush used by terrain edits
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum BrushShape {
+    Sphere,
+    Box,
+}
+
+// Whether a terrain edit adds solid material or carves it away
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum EditMode {
+    Add,
+    Subtract,
+}
+
+// Brush parameters recorded with a terrain edit
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct BrushStroke {
+    pub center: Vector3,
+    pub shape: BrushShape,
+    pub mode: EditMode,
+    pub strength: f32,
+    pub radius: f32,
+    pub material_id: u8,
+}
+
+// What changed a chunk: a brush (terrain edits and mining) or an admin upload of a full field
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum TerrainEditAction {
+    Brush(BrushStroke),
+    Upload(ChunkEncoding),
+}
+
+// Noise layer scales for a planet's base terrain (larger = bigger features)
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct NoiseParams {
+    pub base_scale: f32,
+    pub medium_scale: f32,
+    pub fine_scale: f32,
+}
+
+// Element in a planet's material palette with its relative abundance
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct PlanetMaterial {
+    pub symbol: String,
+    pub abundance: f32,
+}
+
+// Chunk coordinates on a planet; packs into the u64 id used as terrain chunk key
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChunkCoord {
+    pub planet_id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl ChunkCoord {
+    // Layout: planet_id (16 bits) | x (16 bits) | y (16 bits) | z (16 bits), axes as i16
+    pub fn chunk_id(&self) -> Result<u64, String> {
+        let planet_id = u16::try_from(self.planet_id)
+            .map_err(|_| format!("Planet id {} does not fit in a chunk id", self.planet_id))?;
+        let axis = |value: i32| {
+            i16::try_from(value)
+                .map(|v| v as u16 as u64)
+                .map_err(|_| format!("Chunk coordinate {} is out of range for {:?}", value, self))
+        };
+        Ok((planet_id as u64) << 48 | axis(self.x)? << 32 | axis(self.y)? << 16 | axis(self.z)?)
+    }
+
+    pub fn from_chunk_id(chunk_id: u64) -> ChunkCoord {
+        let axis = |shift: u32| (chunk_id >> shift) as u16 as i16 as i32;
+        ChunkCoord {
+            planet_id: (chunk_id >> 48) as u32,
+            x: axis(32),
+            y: axis(16),
+            z: axis(0),
+        }
+    }
+}
+
+// Binary format of a terrain payload; quantized formats are lossy but much smaller
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChunkEncoding {
+    Raw,
+    Quantized16,
+    Quantized8,
+}
+
+// Tool used to mine terrain; better tools mine harder materials with better yields
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToolType {
+    Hand,
+    Pick,
+    Drill,
+    Laser,
+}
+
+// Structure prefabs players can place; matches the client building types
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrefabType {
+    Foundation,
+    Wall,
+    Roof,
+    Window,
+    Door,
+}
+
+// Where players may be on a planet; leaving it respawns them at the planet's spawn point
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum PlanetBounds {
+    Unbounded,   // Anywhere with representable chunk coordinates
+    Sphere(f32), // Within this distance of the planet center (the world origin)
+}
+
+// How a planet orients its players; the planet center is the world origin
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum MovementMode {
+    Flat,      // Up is +Y and gravity pulls towards -Y
+    Spherical, // Up points away from the planet center and gravity pulls towards it
+}
+
+// Survival stats of a player, each from 0 (depleted) to MAX_SURVIVAL_STAT
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct SurvivalStats {
+    pub hunger: f32,
+    pub thirst: f32,
+    pub stamina: f32,
+}
+
+// Quantity of one item, as consumed or produced by a recipe
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct ItemStack {
+    pub item_id: String,
+    pub quantity: u32,
+}
+
 // --- Game Constants ---
 
+pub const GAME_TICK_INTERVAL_MS: u64 = 50; // 20 FPS
+pub const MAX_TICK_DELTA_SECS: f32 = 0.25; // Longest time simulated in one tick; longer stalls are skipped
 pub const PLAYER_SPEED: f32 = 7.5;
 pub const SPRINT_MULTIPLIER: f32 = 1.8;
-pub const MOUSE_SENSITIVITY: f32 = 0.002;
\ No newline at end of file
+pub const MOUSE_SENSITIVITY: f32 = 0.002;
+pub const PLAYER_RADIUS: f32 = 0.4;
+pub const PLAYER_HEIGHT: f32 = 1.8; // Player positions are at the feet
+pub const JUMP_SPEED: f32 = 6.0; // Initial upward velocity of a jump
+pub const TERMINAL_VELOCITY: f32 = 50.0; // Max falling speed
+pub const GROUND_PROBE_DISTANCE: f32 = 0.05; // Gap below the feet still counted as grounded
+pub const WALKABLE_NORMAL_Y: f32 = 0.7; // Surfaces flatter than ~45 degrees count as ground
+pub const COLLISION_ITERATIONS: u32 = 4; // Capsule push-out passes per movement step
+pub const NORMAL_SAMPLE_OFFSET: f32 = 0.5; // Offset of the density samples used for surface normals
+pub const RESPAWN_SEARCH_HEIGHT: i32 = 64; // Units searched above a spawn point for open space
+pub const FRAME_POLE_THRESHOLD: f32 = 0.999; // Above this |up.z| spherical yaw is measured from +Y instead of +Z
+
+// --- Terrain Constants ---
+
+pub const CHUNK_SIZE: i32 = 32;
+pub const CHUNK_SAMPLES: usize = (CHUNK_SIZE + 1) as usize; // Border samples are shared with neighbors
+pub const CHUNK_VOXEL_COUNT: usize = CHUNK_SAMPLES * CHUNK_SAMPLES * CHUNK_SAMPLES;
+pub const SURFACE_LEVEL: f32 = 0.0; // Densities below this are solid
+pub const DELTA_EPSILON: f32 = 1e-4; // Smallest density change stored as a modification
+pub const MAX_BRUSH_RADIUS: f32 = 8.0;
+pub const MAX_BRUSH_STRENGTH: f32 = 1.0;
+pub const DEFAULT_VIEW_RADIUS: u32 = 3; // Chunks streamed around a player on X/Z
+pub const MAX_VIEW_RADIUS: u32 = 8;
+pub const VIEW_RADIUS_VERTICAL: i32 = 1; // Chunk layers streamed above and below a player
+pub const DEFAULT_CHUNK_TTL_SECS: u64 = 600; // Unmodified chunks idle this long are evicted
+pub const DEFAULT_CLEANUP_INTERVAL_SECS: u64 = 60;
+pub const DEFAULT_MAX_STORED_CHUNKS: u32 = 4096; // Per-planet quota of stored chunk rows
+pub const STORED_DELTA_ENCODING: ChunkEncoding = ChunkEncoding::Raw; // Stored edits stay lossless
+
+// --- Mining Constants ---
+
+pub const MINING_REACH: f32 = 6.0; // Max distance from the player to a mined sample
+pub const MINING_RADIUS: f32 = 1.5; // Radius of the sphere carved by one mining action
+pub const MINING_STRENGTH_PER_SPEED: f32 = 0.1; // Density removed per point of tool speed
+
+// --- Inventory Constants ---
+
+pub const DEFAULT_INVENTORY_CAPACITY: u32 = 100; // Total item quantity a player can carry
+pub const DEFAULT_INVENTORY_SLOTS: u32 = 20;
+
+// --- Crafting Constants ---
+
+pub const MAX_CRAFT_QUEUE: usize = 5; // Pending craft jobs per player
+pub const MAX_CRAFT_TIME_MS: u64 = 3_600_000;
+
+// --- Building Constants ---
+
+pub const BUILD_REACH: f32 = 8.0; // Max distance from the player to a placed structure
+pub const STRUCTURE_CONTACT_MARGIN: f32 = 0.05; // Gap still counted as touching; overlap allowed at faces
+pub const STRUCTURE_TERRAIN_TOLERANCE: f32 = 0.25; // Depth a structure may sink into terrain
+
+// --- World Clock Constants ---
+
+pub const HOURS_PER_DAY: f32 = 24.0;
+pub const DAY_LENGTH_SECS: f32 = 1200.0; // Real seconds per day at time scale 1
+pub const DEFAULT_TIME_OF_DAY: f32 = 12.0; // New planets start at noon
+pub const MAX_TIME_SCALE: f32 = 1000.0;
+
+// --- Survival Constants ---
+
+pub const MAX_SURVIVAL_STAT: f32 = 100.0;
+pub const HUNGER_DECAY_PER_SEC: f32 = 0.05;
+pub const THIRST_DECAY_PER_SEC: f32 = 0.08;
+pub const SPRINT_DECAY_MULTIPLIER: f32 = 2.0; // Hunger/thirst decay faster while sprinting
+pub const STAMINA_SPRINT_DRAIN_PER_SEC: f32 = 10.0;
+pub const STAMINA_RECOVERY_PER_SEC: f32 = 5.0;
+pub const DEPLETION_DAMAGE_PER_SEC: i32 = 1; // Health lost per second for each depleted stat
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_id_round_trip() {
+        let coords = [
+            ChunkCoord { planet_id: 0, x: 0, y: 0, z: 0 },
+            ChunkCoord { planet_id: 7, x: -3, y: 12, z: -32768 },
+            ChunkCoord { planet_id: 65535, x: 32767, y: -1, z: 1 },
+            ChunkCoord { planet_id: 1, x: -32768, y: -32768, z: -32768 },
+        ];
+        for coord in coords {
+            let chunk_id = coord.chunk_id().unwrap();
+            assert_eq!(ChunkCoord::from_chunk_id(chunk_id), coord);
+        }
+    }
+
+    #[test]
+    fn test_chunk_id_sign_handling() {
+        // Negative axes must not borrow into the neighboring fields
+        let origin = ChunkCoord { planet_id: 2, x: 0, y: 0, z: 0 }.chunk_id().unwrap();
+        let below = ChunkCoord { planet_id: 2, x: 0, y: 0, z: -1 }.chunk_id().unwrap();
+        assert_eq!(origin, 2 << 48);
+        assert_eq!(below, 2 << 48 | 0xffff);
+        assert_ne!(
+            ChunkCoord { planet_id: 7, x: -3, y: 12, z: 0 }.chunk_id(),
+            ChunkCoord { planet_id: 7, x: 12, y: -3, z: 0 }.chunk_id()
+        );
+    }
+
+    #[test]
+    fn test_chunk_id_rejects_out_of_range() {
+        assert!(ChunkCoord { planet_id: 7, x: 32768, y: 0, z: 0 }.chunk_id().is_err());
+        assert!(ChunkCoord { planet_id: 7, x: 0, y: -32769, z: 0 }.chunk_id().is_err());
+        assert!(ChunkCoord { planet_id: 65536, x: 0, y: 0, z: 0 }.chunk_id().is_err());
+    }
+}
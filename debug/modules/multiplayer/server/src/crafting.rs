@@ -0,0 +1,65 @@
+/*
+ * Infinia Multiplayer - crafting.rs
+ *
+ * This file contains the starter recipes written to the recipe table on init.
+ * After that the table is authoritative, so admins can add or change recipes
+ * with set_recipe without republishing the module.
+ *
+ * Key components:
+ * - RecipeDefinition: Static description of a recipe
+ * - DEFAULT_RECIPES: Starter recipes turning mined elements into parts and stations
+ *
+ * Crafting rules:
+ * - Inputs are consumed when a craft starts and refunded if it is cancelled
+ * - Outputs are credited when the craft completes; stacks that don't fit are dropped at the player
+ * - A required station must be held in the crafter's inventory and is not consumed
+ * - Jobs of one player run one after another
+ *
+ * When modifying:
+ * - Item ids of mined resources are element symbols (see mining.rs)
+ * - Changing DEFAULT_RECIPES only affects modules initialized afterwards
+ */
+
+pub struct RecipeDefinition {
+    pub recipe_id: &'static str,
+    pub name: &'static str,
+    pub inputs: &'static [(&'static str, u32)],
+    pub outputs: &'static [(&'static str, u32)],
+    pub craft_time_secs: u64,
+    pub required_station: Option<&'static str>,
+}
+
+pub const DEFAULT_RECIPES: &[RecipeDefinition] = &[
+    RecipeDefinition {
+        recipe_id: "iron_plate",
+        name: "Iron Plate",
+        inputs: &[("Fe", 3)],
+        outputs: &[("iron_plate", 1)],
+        craft_time_secs: 5,
+        required_station: None,
+    },
+    RecipeDefinition {
+        recipe_id: "copper_wire",
+        name: "Copper Wire",
+        inputs: &[("Cu", 2)],
+        outputs: &[("copper_wire", 4)],
+        craft_time_secs: 3,
+        required_station: None,
+    },
+    RecipeDefinition {
+        recipe_id: "workbench",
+        name: "Workbench",
+        inputs: &[("iron_plate", 4), ("C", 4)],
+        outputs: &[("workbench", 1)],
+        craft_time_secs: 10,
+        required_station: None,
+    },
+    RecipeDefinition {
+        recipe_id: "circuit",
+        name: "Circuit",
+        inputs: &[("copper_wire", 3), ("Si", 2)],
+        outputs: &[("circuit", 1)],
+        craft_time_secs: 8,
+        required_station: Some("workbench"),
+    },
+];
@@ -0,0 +1,76 @@
+/*
+ * Infinia Multiplayer - day_night.rs
+ *
+ * This file contains the day/night clock math. A planet's world_clock row
+ * stores the clock reading at its last change; game_tick re-anchors it once
+ * per in-game minute and clients extrapolate between with advance_clock.
+ *
+ * Key functions:
+ * - advance_clock: Time of day and day count after some real time has passed
+ * - clock_minute: Whole in-game minutes elapsed, used to throttle clock writes
+ *
+ * Clock model (matches debug/modules/wilderness/dayNightSystem.ts):
+ * - Time of day is in hours, 0 <= time < HOURS_PER_DAY (12 = noon)
+ * - At time scale 1 a full day lasts DAY_LENGTH_SECS of real time
+ *
+ * When modifying:
+ * - Clients derive lighting from time_of_day; keep it in hours
+ */
+
+use crate::common::{HOURS_PER_DAY, DAY_LENGTH_SECS};
+
+// Returns (time_of_day, day_count) after elapsed_secs of real time at time_scale
+pub fn advance_clock(time_of_day: f32, day_count: u32, elapsed_secs: f64, time_scale: f32) -> (f32, u32) {
+    let hours = time_of_day as f64 + elapsed_secs * time_scale as f64 * HOURS_PER_DAY as f64 / DAY_LENGTH_SECS as f64;
+    let days = (hours / HOURS_PER_DAY as f64).floor();
+    let time_of_day = (hours - days * HOURS_PER_DAY as f64) as f32 % HOURS_PER_DAY; // f32 rounding may reach 24
+    (time_of_day, day_count.saturating_add(days as u32))
+}
+
+// Whole in-game minutes since the start of day 0
+pub fn clock_minute(time_of_day: f32, day_count: u32) -> u64 {
+    day_count as u64 * (HOURS_PER_DAY as u64 * 60) + (time_of_day * 60.0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_clock_wraps_into_next_days() {
+        let day = DAY_LENGTH_SECS as f64;
+        assert_eq!(advance_clock(12.0, 0, day / 4.0, 1.0), (18.0, 0));
+        assert_eq!(advance_clock(18.0, 3, day / 2.0, 1.0), (6.0, 4));
+        assert_eq!(advance_clock(0.0, 0, day, 2.5), (12.0, 2));
+    }
+
+    #[test]
+    fn test_advance_clock_extrapolates_from_one_anchor() {
+        // Reading the clock once after a long time matches stepping it every tick
+        let (mut time_of_day, mut day_count) = (7.5, 1);
+        for _ in 0..2000 {
+            (time_of_day, day_count) = advance_clock(time_of_day, day_count, 0.05, 3.0);
+        }
+        let (anchored_time, anchored_days) = advance_clock(7.5, 1, 100.0, 3.0);
+        assert_eq!(anchored_days, day_count);
+        assert!((anchored_time - time_of_day).abs() < 1e-3);
+        assert!((0.0..HOURS_PER_DAY).contains(&anchored_time));
+    }
+
+    #[test]
+    fn test_clock_minute_changes_once_per_in_game_minute() {
+        let minute_secs = DAY_LENGTH_SECS as f64 / (HOURS_PER_DAY as f64 * 60.0);
+        let start = clock_minute(10.0, 2);
+        assert_eq!(start, 2 * 1440 + 600);
+
+        // Ticks shorter than an in-game minute leave the minute unchanged until it passes
+        let (time_of_day, day_count) = advance_clock(10.0, 2, minute_secs * 0.5, 1.0);
+        assert_eq!(clock_minute(time_of_day, day_count), start);
+        let (time_of_day, day_count) = advance_clock(10.0, 2, minute_secs * 1.5, 1.0);
+        assert_eq!(clock_minute(time_of_day, day_count), start + 1);
+
+        // Midnight rolls into the next day without repeating a minute
+        let (time_of_day, day_count) = advance_clock(23.99, 2, minute_secs, 1.0);
+        assert!(clock_minute(time_of_day, day_count) > clock_minute(23.99, 2));
+    }
+}
@@ -4,19 +4,72 @@
  * Main entry point for the SpacetimeDB module. This file contains:
  * 
  * 1. Database Schema:
- *    - PlayerData: Active player information
+ *    - PlayerData: Active player information, including survival stats
  *    - LoggedOutPlayerData: Persistent data for disconnected players
  *    - GameTickSchedule: Periodic update scheduling
+ *    - Admin: Identities allowed to call admin reducers
+ *    - Planet: Registry of planets with seeds, generation parameters, bounds, spawn point and movement mode
+ *    - TerrainChunk: Player modifications to a chunk, stored as sparse density and material deltas
+ *      (keyed by the packed ChunkCoord id, indexed by planet and chunk coordinates)
+ *    - MaterializedChunk: Full density field of a chunk, written on request
+ *    - TerrainEdit: Log of server-applied terrain edits and uploads (action, sender, time)
+ *    - ChunkInterest: Chunks near each player, maintained by game_tick for filtered subscriptions
+ *    - ChunkRequest: Latest chunk fetched by each identity through get_terrain_chunk
+ *    - TerrainStorageConfig: Chunk TTL and cleanup interval (singleton)
+ *    - ChunkCleanupSchedule: Periodic terrain cleanup scheduling
+ *    - PlanetTerrainStats: Stored/modified/evicted chunk counts per planet
+ *    - Inventory: Capacity and slot count of each player's inventory
+ *    - InventoryItem: Item stacks held by each player, one per inventory slot, kept across logouts
+ *    - DroppedItem: Item stacks dropped into the world by players
+ *    - WorldClock: Time of day and day count of each planet, re-anchored once per in-game minute
+ *    - Structure: Prefabs placed by players (owner, position, rotation, health), indexed by chunk
+ *    - Tool: Mining tools owned by players with their remaining durability
+ *    - Recipe: Crafting recipes (inputs, outputs, crafting time, required station)
+ *    - CraftJob: Pending crafts, each scheduled to complete at its finish time
  * 
  * 2. Reducer Functions (Server Endpoints):
  *    - init: Module initialization and game tick scheduling
  *    - identity_connected/disconnected: Connection lifecycle management
  *    - register_player: Player registration with username
- *    - update_player_input: Processes player movement and state updates
- *    - game_tick: Periodic update for game state (scheduled)
+ *    - update_player_input: Stores player input and applies mouse look; game_tick moves players
+ *    - add_admin: Grants admin rights to another identity (admin only)
+ *    - create_planet/tune_planet: Registers and adjusts planets (admin only)
+ *    - set_planet_bounds: Sets where players may go on a planet and where they respawn (admin only)
+ *    - set_planet_movement_mode: Switches a planet between flat and spherical movement and terrain (admin only)
+ *    - store_terrain_chunk: Stores an uploaded field as deltas against the procedural base (admin only),
+ *      rejecting writes based on a stale chunk version, copying its border samples to neighbors
+ *      and logging each changed chunk in terrain_edit
+ *    - get_terrain_chunk: Writes a chunk's full field (generated on demand) to the caller's chunk_request
+ *    - store_initial_chunks_for_planet: Registers unmodified chunks around the origin
+ *    - materialize_terrain_chunk: Expands a chunk's deltas into its full density and material fields
+ *    - apply_terrain_edit: Applies a brush edit within reach of the player to every chunk it overlaps
+ *      in one transaction and logs it, optionally rejecting it when the chunk at the brush center
+ *      moved past the client's version; added terrain is paid for with inventory material and
+ *      placed terrain carved away is refunded
+ *    - enter_planet/set_view_radius: Choose the planet and chunk radius a player streams
+ *    - cleanup_terrain_chunks: Evicts idle unmodified chunks, LRU first (scheduled)
+ *    - set_terrain_storage_config/set_planet_chunk_quota: Tune terrain storage (admin only)
+ *    - mine_terrain: Carves terrain within reach of the player, credits each generated sample
+ *      carved to air (placed material is only refunded) and wears down the tool used
+ *    - repair_tool/upgrade_tool: Restore or improve a tool by consuming inventory resources
+ *    - grant_tool: Gives a player a new tool (admin only)
+ *    - set_recipe/remove_recipe: Manage crafting recipes (admin only)
+ *    - start_craft/cancel_craft: Queue a craft paid from the inventory, or cancel it for a refund
+ *    - complete_craft: Credits a finished craft's outputs (scheduled)
+ *    - consume_item: Eats or drinks an item from the caller's inventory
+ *    - place_structure/remove_structure: Build and tear down structures, checked against terrain,
+ *      other structures and players
+ *    - add_inventory_item: Grants items to a player's inventory (admin only)
+ *    - remove/move/split/drop_inventory_item: Manage the caller's inventory slots
+ *    - set_world_time/set_time_scale/set_world_clock_paused: Control planet clocks (admin only)
+ *    - game_tick: Periodic update for player movement, survival stats and world clocks (scheduled)
  * 
  * 3. Table Structure:
  *    - All tables use Identity as primary keys where appropriate
+ *    - Terrain tables are keyed by ChunkCoord::chunk_id and reference planets by planet_id
+ *    - Terrain payloads are byte vectors tagged with the ChunkEncoding they were written in
+ *    - Usernames are unique across active and logged out players
+ *    - Look rows up through primary key, unique or btree index accessors, never table scans
  *    - Connection between tables maintained through identity references
  * 
  * When modifying:
@@ -29,18 +82,63 @@
  * Related files:
  *    - common.rs: Shared data structures used in table definitions
  *    - player_logic.rs: Player movement and state update calculations
+ *    - terrain.rs: Chunk indexing and terrain brush calculations
+ *    - noise.rs: Deterministic procedural density generation per planet type
+ *    - chunk_codec.rs: Quantized/run-length encodings for terrain payloads
+ *    - mining.rs: Tool stats, material hardness/value tables and mining yields
+ *    - crafting.rs: Default crafting recipes
+ *    - building.rs: Prefab sizes and bounds used to validate structure placement
+ *    - day_night.rs: Day/night clock advancement
+ *    - survival.rs: Hunger, thirst and stamina rules and consumable items
  */
 
 // Declare modules
 mod common;
 mod player_logic;
+mod noise;
+mod terrain;
+mod chunk_codec;
+mod mining;
+mod crafting;
+mod building;
+mod day_night;
+mod survival;
 
 use spacetimedb::{ReducerContext, Identity, Table, Timestamp, ScheduleAt};
 use std::time::Duration;
 
 // Use items from common module
-use crate::common::{Vector3, InputState};
-use crate::player_logic::{update_player_position, is_significant_movement, is_significant_rotation};
+use crate::common::{
+    Vector3, InputState, ChunkCoord, ChunkEncoding, BrushShape, EditMode, BrushStroke, TerrainEditAction,
+    NoiseParams, PlanetMaterial,
+    CHUNK_VOXEL_COUNT, MAX_BRUSH_RADIUS, MAX_BRUSH_STRENGTH, DEFAULT_VIEW_RADIUS, MAX_VIEW_RADIUS,
+    DEFAULT_CHUNK_TTL_SECS, DEFAULT_CLEANUP_INTERVAL_SECS, DEFAULT_MAX_STORED_CHUNKS,
+    STORED_DELTA_ENCODING, SURFACE_LEVEL, ToolType, MINING_REACH, MINING_RADIUS, MINING_STRENGTH_PER_SPEED,
+    DEFAULT_INVENTORY_CAPACITY, DEFAULT_INVENTORY_SLOTS, ItemStack, MAX_CRAFT_QUEUE, MAX_CRAFT_TIME_MS,
+    PrefabType, BUILD_REACH, STRUCTURE_CONTACT_MARGIN, STRUCTURE_TERRAIN_TOLERANCE,
+    HOURS_PER_DAY, DEFAULT_TIME_OF_DAY, MAX_TIME_SCALE, SurvivalStats, GAME_TICK_INTERVAL_MS, MAX_TICK_DELTA_SECS,
+    DEPLETION_DAMAGE_PER_SEC, PlanetBounds, PLAYER_HEIGHT, RESPAWN_SEARCH_HEIGHT, MovementMode,
+};
+use crate::chunk_codec::{
+    encode_field, decode_field, encode_deltas, decode_deltas, encoding_precision, encoded_value,
+    encode_materials, decode_materials, encode_material_deltas, decode_material_deltas,
+};
+use crate::player_logic::{
+    MovementState, update_player_position, update_player_rotation, validate_position, local_frame, is_significant_movement, is_significant_rotation, calculate_distance,
+};
+use crate::crafting::DEFAULT_RECIPES;
+use crate::day_night::{advance_clock, clock_minute};
+use crate::survival::item_effect;
+use crate::building::{Bounds, prefab_max_health, structure_bounds, player_bounds};
+use crate::mining::{tool_stats, material_hardness, mining_yield, tool_damage, repair_cost, upgrade_path};
+use crate::noise::{TerrainGenerator, TerrainShape, default_noise_params, planet_seed};
+use crate::terrain::{
+    Brush, ChunkDeltas, MaterialChanges, apply_brush, changed_samples, chunk_origin, chunks_in_view, chunks_touched_by_brush,
+    copy_shared_samples, diff_against_base, diff_materials, generate_base_field, generate_base_materials,
+    materialize_field,
+    nearest_sample, neighbor_coords, shared_samples, trilinear, world_to_chunk,
+};
+use std::collections::{BTreeMap, BTreeSet};
 
 // --- Schema Definitions ---
 
@@ -49,18 +147,26 @@ use crate::player_logic::{update_player_position, is_significant_movement, is_si
 pub struct PlayerData {
     #[primary_key]
     identity: Identity,
+    #[unique]
     username: String,
+    planet_id: Option<u32>, // None until the player enters a planet
+    #[index(btree)]
+    chunk_id: u64,          // Chunk containing the player, 0 while not on a planet
+    view_radius: u32,       // Chunks streamed around the player on X/Z
     position: Vector3,
     rotation: Vector3,
     health: i32,
     max_health: i32,
     mana: i32,
     max_mana: i32,
+    survival: SurvivalStats,
+    vertical_velocity: f32, // Positive is up
+    grounded: bool,
     is_moving: bool,
     is_running: bool,
     last_input_seq: u32,
     input: InputState,
-    last_update: Timestamp,
+    last_update: Timestamp, // When game_tick last simulated the player
 }
 
 #[spacetimedb::table(name = logged_out_player)]
@@ -68,13 +174,17 @@ pub struct PlayerData {
 pub struct LoggedOutPlayerData {
     #[primary_key]
     identity: Identity,
+    #[unique]
     username: String,
+    planet_id: Option<u32>,
+    view_radius: u32,
     position: Vector3,
     rotation: Vector3,
     health: i32,
     max_health: i32,
     mana: i32,
     max_mana: i32,
+    survival: SurvivalStats,
     last_seen: Timestamp,
 }
 
@@ -86,20 +196,318 @@ pub struct GameTickSchedule {
     scheduled_at: ScheduleAt,
 }
 
-#[spacetimedb::table(name = terrain_chunk, public)]
+#[spacetimedb::table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    identity: Identity,
+    added_at: Timestamp,
+}
+
+#[spacetimedb::table(name = planet, public)]
+#[derive(Clone)]
+pub struct Planet {
+    #[primary_key]
+    #[auto_inc]
+    planet_id: u32,
+    #[unique]
+    name: String, // Planet type used by the client, e.g. "barren_planet"
+    seed: f64,    // In [0, 1), like the client's Math.random() seeds
+    radius: f32,
+    gravity: f32,
+    materials: Vec<PlanetMaterial>,
+    noise: NoiseParams,
+    bounds: PlanetBounds,
+    spawn_point: Vector3,   // Players are respawned in open space at or above this point
+    movement_mode: MovementMode,
+    max_stored_chunks: u32, // Unmodified chunks beyond this are evicted LRU first
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+impl Planet {
+    fn generator(&self) -> TerrainGenerator {
+        TerrainGenerator::new(self.seed, &self.noise, &self.materials, self.terrain_shape())
+    }
+    
+    // Spherical planets generate a ball of terrain so the surface matches their gravity
+    fn terrain_shape(&self) -> TerrainShape {
+        match self.movement_mode {
+            MovementMode::Flat => TerrainShape::Flat,
+            MovementMode::Spherical => TerrainShape::Sphere(self.radius),
+        }
+    }
+}
+
+#[spacetimedb::table(
+    name = terrain_chunk,
+    public,
+    index(name = planet_chunk, btree(columns = [planet_id, chunk_x, chunk_y, chunk_z]))
+)]
 #[derive(Clone)]
 pub struct TerrainChunk {
     #[primary_key]
-    chunk_key: String, // Format: "x,y,z_planetType"
-    planet_type: String,
+    chunk_id: u64, // ChunkCoord::chunk_id of (planet_id, chunk_x, chunk_y, chunk_z)
+    planet_id: u32,
     chunk_x: i32,
     chunk_y: i32,
     chunk_z: i32,
-    noise_data: Vec<f32>, // Flattened 3D noise map
+    delta_encoding: ChunkEncoding, // Format of delta_data values
+    delta_data: Vec<u8>,           // Voxels that differ from the procedural base (encode_deltas)
+    modified_voxels: u32,          // Number of entries in delta_data
+    material_data: Vec<u8>,        // Voxels whose material differs from the generated one (encode_material_deltas)
+    modified_materials: u32,       // Number of entries in material_data
+    version: u64,                  // Bumped by every write; 0 = never written since generation
     created_at: Timestamp,
     last_accessed: Timestamp,
 }
 
+impl TerrainChunk {
+    fn is_modified(&self) -> bool {
+        self.modified_voxels > 0 || self.modified_materials > 0
+    }
+
+    fn coord(&self) -> ChunkCoord {
+        ChunkCoord {
+            planet_id: self.planet_id,
+            x: self.chunk_x,
+            y: self.chunk_y,
+            z: self.chunk_z,
+        }
+    }
+
+    fn deltas(&self) -> Result<ChunkDeltas, String> {
+        let corrupt = |e: String| format!("Terrain chunk {:?} has corrupt deltas: {}", self.coord(), e);
+        Ok(ChunkDeltas {
+            densities: decode_deltas(&self.delta_data, self.delta_encoding).map_err(corrupt)?,
+            materials: decode_material_deltas(&self.material_data).map_err(corrupt)?,
+        })
+    }
+
+    fn set_deltas(&mut self, deltas: &ChunkDeltas) {
+        self.delta_encoding = STORED_DELTA_ENCODING;
+        self.delta_data = encode_deltas(&deltas.densities, STORED_DELTA_ENCODING);
+        self.modified_voxels = deltas.densities.len() as u32;
+        self.material_data = encode_material_deltas(&deltas.materials);
+        self.modified_materials = deltas.materials.len() as u32;
+    }
+
+    // Full density and material fields
+    fn materialize(&self, generator: &TerrainGenerator) -> Result<(Vec<f32>, Vec<u8>), String> {
+        let origin = chunk_origin(&self.coord());
+        let deltas = self.deltas()?;
+        Ok((
+            materialize_field(generate_base_field(generator, &origin), &deltas.densities),
+            materialize_field(generate_base_materials(generator, &origin), &deltas.materials),
+        ))
+    }
+}
+
+#[spacetimedb::table(name = materialized_chunk, public)]
+#[derive(Clone)]
+pub struct MaterializedChunk {
+    #[primary_key]
+    chunk_id: u64,
+    encoding: ChunkEncoding,
+    field_data: Vec<u8>,    // Full density field, indexed [y][z][x], encoded with encode_field
+    material_data: Vec<u8>, // Palette index per sample, encoded with encode_materials
+    materialized_at: Timestamp,
+}
+
+#[spacetimedb::table(name = terrain_edit, public)]
+#[derive(Clone)]
+pub struct TerrainEdit {
+    #[primary_key]
+    #[auto_inc]
+    edit_id: u64,
+    #[index(btree)]
+    chunk_id: u64,
+    planet_id: u32,
+    sender: Identity,
+    action: TerrainEditAction,
+    voxels_modified: u32,
+    chunk_version: u64, // Chunk version produced by this edit
+    applied_at: Timestamp,
+}
+
+// Chunks near a player. Clients subscribe to their own rows
+// ("SELECT * FROM chunk_interest WHERE identity = :sender") and join
+// terrain_chunk and player on chunk_id to receive only nearby data.
+#[spacetimedb::table(name = chunk_interest, public)]
+#[derive(Clone)]
+pub struct ChunkInterest {
+    #[primary_key]
+    #[auto_inc]
+    interest_id: u64,
+    #[index(btree)]
+    identity: Identity,
+    #[index(btree)]
+    chunk_id: u64,
+    planet_id: u32,
+    chunk_x: i32,
+    chunk_y: i32,
+    chunk_z: i32,
+}
+
+// Response to get_terrain_chunk, one row per requester. Clients subscribe to
+// "SELECT * FROM chunk_request WHERE requester = :sender" and read each update.
+#[spacetimedb::table(name = chunk_request, public)]
+#[derive(Clone)]
+pub struct ChunkRequest {
+    #[primary_key]
+    requester: Identity,
+    chunk_id: u64,
+    coord: ChunkCoord,
+    version: u64,        // Version to name as base_version when storing edits of this field
+    encoding: ChunkEncoding,
+    field_data: Vec<u8>,    // Full density field, indexed [y][z][x], encoded with encode_field
+    material_data: Vec<u8>, // Palette index per sample, encoded with encode_materials
+    requested_at: Timestamp,
+}
+
+#[spacetimedb::table(name = terrain_storage_config)]
+#[derive(Clone)]
+pub struct TerrainStorageConfig {
+    #[primary_key]
+    config_id: u32, // Always 0
+    chunk_ttl_secs: u64,
+    cleanup_interval_secs: u64,
+    updated_at: Timestamp,
+}
+
+#[spacetimedb::table(name = chunk_cleanup_schedule, scheduled(cleanup_terrain_chunks))]
+pub struct ChunkCleanupSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[spacetimedb::table(name = planet_terrain_stats, public)]
+#[derive(Clone)]
+pub struct PlanetTerrainStats {
+    #[primary_key]
+    planet_id: u32,
+    stored_chunks: u32,
+    modified_chunks: u32,
+    modified_voxels: u64,
+    evicted_last_cleanup: u32,
+    evicted_total: u64,
+    last_cleanup: Timestamp,
+}
+
+#[spacetimedb::table(name = inventory, public)]
+#[derive(Clone)]
+pub struct Inventory {
+    #[primary_key]
+    owner: Identity,
+    capacity: u32,   // Max total quantity across all slots
+    slot_count: u32, // Slots are numbered 0..slot_count
+}
+
+// One stack per occupied slot; an owner never has two rows with the same slot
+#[spacetimedb::table(name = inventory_item, public)]
+#[derive(Clone)]
+pub struct InventoryItem {
+    #[primary_key]
+    #[auto_inc]
+    entry_id: u64,
+    #[index(btree)]
+    owner: Identity,
+    item_id: String, // Element symbol for mined resources
+    quantity: u32,
+    slot: u32,
+}
+
+#[spacetimedb::table(name = tool, public)]
+#[derive(Clone)]
+pub struct Tool {
+    #[primary_key]
+    #[auto_inc]
+    tool_id: u64,
+    #[index(btree)]
+    owner: Identity,
+    tool_type: ToolType,
+    durability: u32, // Broken at 0 until repaired
+    max_durability: u32,
+    created_at: Timestamp,
+}
+
+#[spacetimedb::table(name = recipe, public)]
+#[derive(Clone)]
+pub struct Recipe {
+    #[primary_key]
+    recipe_id: String,
+    name: String,
+    inputs: Vec<ItemStack>,
+    outputs: Vec<ItemStack>,
+    craft_time_ms: u64,
+    required_station: Option<String>, // Item that must be in the crafter's inventory
+}
+
+#[spacetimedb::table(name = craft_job, public, scheduled(complete_craft))]
+#[derive(Clone)]
+pub struct CraftJob {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt, // Always ScheduleAt::Time(completes_at)
+    #[index(btree)]
+    owner: Identity,
+    recipe_id: String,
+    inputs: Vec<ItemStack>, // Inputs actually consumed, refunded on cancel
+    started_at: Timestamp,
+    completes_at: Timestamp,
+}
+
+// One row per planet, rewritten by game_tick once per in-game minute and when an admin changes
+// the clock. The row is the clock reading at updated_at; clients that need finer time get it
+// with advance_clock(time_of_day, day_count, now - updated_at, time_scale), or the stored time
+// while paused
+#[spacetimedb::table(name = world_clock, public)]
+#[derive(Clone)]
+pub struct WorldClock {
+    #[primary_key]
+    planet_id: u32,
+    time_of_day: f32, // Hours, 0 <= time < 24, at updated_at
+    day_count: u32,
+    time_scale: f32,  // 1 = one day per DAY_LENGTH_SECS
+    paused: bool,
+    updated_at: Timestamp,
+}
+
+#[spacetimedb::table(name = structure, public)]
+#[derive(Clone)]
+pub struct Structure {
+    #[primary_key]
+    #[auto_inc]
+    structure_id: u64,
+    #[index(btree)]
+    owner: Identity,
+    prefab: PrefabType,
+    planet_id: u32,
+    #[index(btree)]
+    chunk_id: u64,      // Chunk containing the structure position
+    position: Vector3,  // Center of the prefab's box
+    rotation: Vector3,  // Only the yaw (y) turns the box
+    health: u32,
+    max_health: u32,
+    placed_at: Timestamp,
+}
+
+#[spacetimedb::table(name = dropped_item, public)]
+#[derive(Clone)]
+pub struct DroppedItem {
+    #[primary_key]
+    #[auto_inc]
+    drop_id: u64,
+    planet_id: Option<u32>,
+    position: Vector3,
+    item_id: String,
+    quantity: u32,
+    dropped_by: Identity,
+    dropped_at: Timestamp,
+}
+
 // --- Lifecycle Reducers ---
 
 #[spacetimedb::reducer(init)]
@@ -108,39 +516,90 @@ pub fn init(ctx: &ReducerContext) -> Result<(), String> {
     
     if ctx.db.game_tick_schedule().count() == 0 {
         spacetimedb::log::info!("[INIT] Scheduling initial game tick (every 50ms)...");
-        let loop_duration = Duration::from_millis(50); // 20 FPS
+        let loop_duration = Duration::from_millis(GAME_TICK_INTERVAL_MS);
         ctx.db.game_tick_schedule().insert(GameTickSchedule {
             scheduled_id: 0,
-            scheduled_at: ScheduleAt::Interval(loop_duration),
-        })?;
+            scheduled_at: ScheduleAt::Interval(loop_duration.into()),
+        });
         spacetimedb::log::info!("[INIT] Game tick scheduled successfully.");
     } else {
         spacetimedb::log::info!("[INIT] Game tick already scheduled.");
     }
     
+    if ctx.db.terrain_storage_config().config_id().find(0).is_none() {
+        ctx.db.terrain_storage_config().insert(TerrainStorageConfig {
+            config_id: 0,
+            chunk_ttl_secs: DEFAULT_CHUNK_TTL_SECS,
+            cleanup_interval_secs: DEFAULT_CLEANUP_INTERVAL_SECS,
+            updated_at: ctx.timestamp,
+        });
+    }
+    
+    if ctx.db.chunk_cleanup_schedule().count() == 0 {
+        spacetimedb::log::info!("[INIT] Scheduling terrain cleanup (every {}s)...", DEFAULT_CLEANUP_INTERVAL_SECS);
+        ctx.db.chunk_cleanup_schedule().insert(ChunkCleanupSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(Duration::from_secs(DEFAULT_CLEANUP_INTERVAL_SECS).into()),
+        });
+    }
+    
+    if ctx.db.recipe().count() == 0 {
+        for definition in DEFAULT_RECIPES {
+            let stacks = |items: &[(&str, u32)]| {
+                items.iter()
+                    .map(|&(item_id, quantity)| ItemStack { item_id: item_id.to_string(), quantity })
+                    .collect()
+            };
+            ctx.db.recipe().insert(Recipe {
+                recipe_id: definition.recipe_id.to_string(),
+                name: definition.name.to_string(),
+                inputs: stacks(definition.inputs),
+                outputs: stacks(definition.outputs),
+                craft_time_ms: definition.craft_time_secs * 1000,
+                required_station: definition.required_station.map(str::to_string),
+            });
+        }
+        spacetimedb::log::info!("[INIT] Registered {} default recipes", DEFAULT_RECIPES.len());
+    }
+    
+    // The identity that publishes the module administers it
+    if ctx.db.admin().identity().find(ctx.sender).is_none() {
+        ctx.db.admin().insert(Admin {
+            identity: ctx.sender,
+            added_at: ctx.timestamp,
+        });
+        spacetimedb::log::info!("[INIT] Registered module owner {} as admin", ctx.sender.to_hex());
+    }
+    
     spacetimedb::log::info!("[INIT] Infinia Multiplayer module initialized successfully.");
     Ok(())
 }
 
-#[spacetimedb::reducer]
+#[spacetimedb::reducer(client_connected)]
 pub fn identity_connected(ctx: &ReducerContext) -> Result<(), String> {
     let identity = ctx.sender;
     spacetimedb::log::info!("[CONNECTION] Identity connected: {}", identity.to_hex());
     
     // Check if player was previously logged out
-    if let Some(logged_out_player) = ctx.db.logged_out_player().find(|p| p.identity == identity) {
+    if let Some(logged_out_player) = ctx.db.logged_out_player().identity().find(identity) {
         spacetimedb::log::info!("[CONNECTION] Restoring logged out player: {}", logged_out_player.username);
         
         // Restore player to active table
-        ctx.db.player().insert(PlayerData {
+        let mut player = PlayerData {
             identity,
             username: logged_out_player.username.clone(),
+            planet_id: logged_out_player.planet_id,
+            chunk_id: 0,
+            view_radius: logged_out_player.view_radius,
             position: logged_out_player.position.clone(),
             rotation: logged_out_player.rotation.clone(),
             health: logged_out_player.health,
             max_health: logged_out_player.max_health,
             mana: logged_out_player.mana,
             max_mana: logged_out_player.max_mana,
+            survival: logged_out_player.survival.clone(),
+            vertical_velocity: 0.0,
+            grounded: false,
             is_moving: false,
             is_running: false,
             last_input_seq: 0,
@@ -152,10 +611,12 @@ pub fn identity_connected(ctx: &ReducerContext) -> Result<(), String> {
                 sequence: 0,
             },
             last_update: ctx.timestamp,
-        })?;
+        };
+        refresh_chunk_interest(ctx, &mut player);
+        ctx.db.player().insert(player);
         
         // Remove from logged out table
-        ctx.db.logged_out_player().delete(&logged_out_player);
+        ctx.db.logged_out_player().identity().delete(identity);
         
         spacetimedb::log::info!("[CONNECTION] Player {} restored successfully", logged_out_player.username);
     }
@@ -163,88 +624,474 @@ pub fn identity_connected(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
-#[spacetimedb::reducer]
+#[spacetimedb::reducer(client_disconnected)]
 pub fn identity_disconnected(ctx: &ReducerContext) -> Result<(), String> {
     let identity = ctx.sender;
     spacetimedb::log::info!("[DISCONNECTION] Identity disconnected: {}", identity.to_hex());
     
     // Find and move player to logged out table
-    if let Some(player) = ctx.db.player().find(|p| p.identity == identity) {
+    if let Some(player) = ctx.db.player().identity().find(identity) {
         spacetimedb::log::info!("[DISCONNECTION] Moving player {} to logged out table", player.username);
         
         // Save to logged out table
         ctx.db.logged_out_player().insert(LoggedOutPlayerData {
             identity,
             username: player.username.clone(),
+            planet_id: player.planet_id,
+            view_radius: player.view_radius,
             position: player.position.clone(),
             rotation: player.rotation.clone(),
             health: player.health,
             max_health: player.max_health,
             mana: player.mana,
             max_mana: player.max_mana,
+            survival: player.survival.clone(),
             last_seen: ctx.timestamp,
-        })?;
+        });
         
-        // Remove from active table
-        ctx.db.player().delete(&player);
+        // Remove from active table; interest is rebuilt on reconnect.
+        // Inventory rows are keyed by identity and wait for the player's return.
+        ctx.db.player().identity().delete(identity);
+        clear_chunk_interest(ctx, identity);
         
         spacetimedb::log::info!("[DISCONNECTION] Player {} moved to logged out table", player.username);
     }
     
+    // Pending chunk responses are only useful to a connected client
+    ctx.db.chunk_request().requester().delete(identity);
+    
+    Ok(())
+}
+
+// --- Planet Management Reducers ---
+
+fn require_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.admin().identity().find(ctx.sender).is_none() {
+        return Err(format!("Identity {} is not an admin", ctx.sender.to_hex()));
+    }
+    Ok(())
+}
+
+fn find_planet(ctx: &ReducerContext, planet_id: u32) -> Result<Planet, String> {
+    ctx.db.planet()
+        .planet_id()
+        .find(planet_id)
+        .ok_or_else(|| format!("Unknown planet with id {}", planet_id))
+}
+
+fn validate_planet_params(
+    seed: f64,
+    radius: f32,
+    gravity: f32,
+    materials: &[PlanetMaterial],
+    noise: &NoiseParams,
+) -> Result<(), String> {
+    if !(0.0..1.0).contains(&seed) {
+        return Err(format!("Planet seed {} must be in [0, 1)", seed));
+    }
+    if radius.is_nan() || radius <= 0.0 {
+        return Err(format!("Planet radius {} must be positive", radius));
+    }
+    if gravity.is_nan() || gravity < 0.0 {
+        return Err(format!("Planet gravity {} must not be negative", gravity));
+    }
+    if !(noise.base_scale > 0.0 && noise.medium_scale > 0.0 && noise.fine_scale > 0.0) {
+        return Err("Noise layer scales must be positive".to_string());
+    }
+    if let Some(material) = materials.iter().find(|m| m.symbol.is_empty() || m.abundance.is_nan() || m.abundance < 0.0) {
+        return Err(format!("Invalid material '{}' with abundance {}", material.symbol, material.abundance));
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn add_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    
+    if ctx.db.admin().identity().find(identity).is_some() {
+        return Err(format!("Identity {} is already an admin", identity.to_hex()));
+    }
+    
+    ctx.db.admin().insert(Admin {
+        identity,
+        added_at: ctx.timestamp,
+    });
+    spacetimedb::log::info!("[ADMIN] {} granted admin rights to {}", ctx.sender.to_hex(), identity.to_hex());
+    
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn create_planet(
+    ctx: &ReducerContext,
+    name: String,
+    seed: Option<f64>,
+    radius: f32,
+    gravity: f32,
+    materials: Vec<PlanetMaterial>,
+    noise: Option<NoiseParams>,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    
+    if name.is_empty() {
+        return Err("Planet name must not be empty".to_string());
+    }
+    if ctx.db.planet().name().find(&name).is_some() {
+        return Err(format!("Planet '{}' already exists", name));
+    }
+    
+    // Fall back to the same defaults the client uses for this planet type
+    let seed = seed.unwrap_or_else(|| planet_seed(&name));
+    let noise = noise.unwrap_or_else(|| default_noise_params(&name));
+    validate_planet_params(seed, radius, gravity, &materials, &noise)?;
+    
+    let planet = ctx.db.planet().insert(Planet {
+        planet_id: 0,
+        name: name.clone(),
+        seed,
+        radius,
+        gravity,
+        materials,
+        noise,
+        bounds: PlanetBounds::Unbounded,
+        spawn_point: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+        movement_mode: MovementMode::Flat,
+        max_stored_chunks: DEFAULT_MAX_STORED_CHUNKS,
+        created_at: ctx.timestamp,
+        updated_at: ctx.timestamp,
+    });
+    
+    ensure_world_clock(ctx, planet.planet_id);
+    
+    spacetimedb::log::info!("[PLANET] Created planet {} with id {}", name, planet.planet_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn tune_planet(
+    ctx: &ReducerContext,
+    planet_id: u32,
+    seed: f64,
+    radius: f32,
+    gravity: f32,
+    materials: Vec<PlanetMaterial>,
+    noise: NoiseParams,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    validate_planet_params(seed, radius, gravity, &materials, &noise)?;
+    
+    let mut planet = find_planet(ctx, planet_id)?;
+    
+    // Stored deltas are absolute densities, so edits survive; unmodified terrain regenerates
+    let reshaped = planet.movement_mode == MovementMode::Spherical && planet.radius != radius;
+    if planet.seed != seed || planet.noise != noise || reshaped {
+        spacetimedb::log::warn!("[PLANET] Generation parameters of {} changed; unmodified terrain will differ", planet.name);
+    }
+    
+    planet.seed = seed;
+    planet.radius = radius;
+    planet.gravity = gravity;
+    planet.materials = materials;
+    planet.noise = noise;
+    planet.updated_at = ctx.timestamp;
+    
+    spacetimedb::log::info!("[PLANET] Tuned planet {} ({})", planet.name, planet_id);
+    ctx.db.planet().planet_id().update(planet);
+    
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_planet_bounds(ctx: &ReducerContext, planet_id: u32, bounds: PlanetBounds, spawn_point: Vector3) -> Result<(), String> {
+    require_admin(ctx)?;
+    let mut planet = find_planet(ctx, planet_id)?;
+    
+    if let PlanetBounds::Sphere(radius) = bounds {
+        if !(radius.is_finite() && radius > 0.0) {
+            return Err(format!("Bounds radius {} must be positive", radius));
+        }
+    }
+    planet.bounds = bounds;
+    planet.spawn_point = spawn_point;
+    if !is_in_planet_bounds(&planet, &planet.spawn_point) {
+        return Err(format!("Spawn point {:?} is outside the planet bounds", planet.spawn_point));
+    }
+    if planet.movement_mode == MovementMode::Spherical && is_near_planet_center(&planet.spawn_point) {
+        return Err(format!("Spawn point {:?} is at the center of spherical planet {}", planet.spawn_point, planet.name));
+    }
+    planet.updated_at = ctx.timestamp;
+    
+    spacetimedb::log::info!("[PLANET] Planet {} bounds set to {:?}", planet.name, planet.bounds);
+    ctx.db.planet().planet_id().update(planet);
+    
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_planet_movement_mode(ctx: &ReducerContext, planet_id: u32, mode: MovementMode) -> Result<(), String> {
+    require_admin(ctx)?;
+    let mut planet = find_planet(ctx, planet_id)?;
+    
+    if planet.movement_mode != mode {
+        spacetimedb::log::warn!("[PLANET] Terrain shape of {} changed; unmodified terrain will differ", planet.name);
+    }
+    planet.movement_mode = mode;
+    
+    // Up is undefined at the center of a spherical planet; spawn on its surface above it instead
+    if mode == MovementMode::Spherical && is_near_planet_center(&planet.spawn_point) {
+        planet.spawn_point = Vector3 { x: 0.0, y: planet.radius, z: 0.0 };
+        if !is_in_planet_bounds(&planet, &planet.spawn_point) {
+            return Err(format!("Surface spawn point {:?} is outside the planet bounds", planet.spawn_point));
+        }
+        spacetimedb::log::info!("[PLANET] Planet {} spawn point moved to its surface", planet.name);
+    }
+    planet.updated_at = ctx.timestamp;
+    
+    spacetimedb::log::info!("[PLANET] Planet {} movement mode set to {:?}", planet.name, mode);
+    ctx.db.planet().planet_id().update(planet);
+    
     Ok(())
 }
 
+fn is_near_planet_center(position: &Vector3) -> bool {
+    calculate_distance(position, &Vector3 { x: 0.0, y: 0.0, z: 0.0 }) < PLAYER_HEIGHT
+}
+
+// Positions must be within the planet bounds and in a chunk that can be stored
+fn is_in_planet_bounds(planet: &Planet, position: &Vector3) -> bool {
+    validate_position(position, &planet.bounds) && world_to_chunk(planet.planet_id, position).chunk_id().is_ok()
+}
+
+// Moves a player to the first open space at or above the planet's spawn point
+fn respawn_player(ctx: &ReducerContext, player: &mut PlayerData, planet: &Planet) {
+    let mut terrain = TerrainSampler::new(ctx, planet);
+    let spawn = &planet.spawn_point;
+    let up = local_frame(spawn, planet.movement_mode).up;
+    let above = |height: f32| Vector3 { x: spawn.x + up.x * height, y: spawn.y + up.y * height, z: spawn.z + up.z * height };
+    let mut is_open = |height: f32| {
+        !terrain.is_solid(&above(height)).unwrap_or(true) && !terrain.is_solid(&above(height + PLAYER_HEIGHT)).unwrap_or(true)
+    };
+    
+    player.position = (0..=RESPAWN_SEARCH_HEIGHT)
+        .map(|height| height as f32)
+        .find(|&height| is_open(height))
+        .map(above)
+        .unwrap_or_else(|| spawn.clone());
+    player.vertical_velocity = 0.0;
+    player.grounded = false;
+    
+    spacetimedb::log::info!(
+        "[RESPAWN] Player {} respawned on {} at ({:.1}, {:.1}, {:.1})",
+        player.username,
+        planet.name,
+        player.position.x,
+        player.position.y,
+        player.position.z
+    );
+}
+
 // --- Terrain Management Reducers ---
 
 #[spacetimedb::reducer]
 pub fn store_terrain_chunk(
     ctx: &ReducerContext,
-    chunk_key: String,
-    planet_type: String,
-    chunk_x: i32,
-    chunk_y: i32,
-    chunk_z: i32,
-    noise_data: Vec<f32>,
+    coord: ChunkCoord,
+    base_version: u64,
+    encoding: ChunkEncoding,
+    field_data: Vec<u8>,
+    material_data: Option<Vec<u8>>, // Palette indices; None keeps the chunk's current materials
 ) -> Result<(), String> {
-    spacetimedb::log::info!("[TERRAIN] Storing terrain chunk: {}", chunk_key);
+    // Uploads replace terrain and materials wholesale, bypassing brush limits and inventory costs
+    require_admin(ctx)?;
+    
+    spacetimedb::log::info!("[TERRAIN] Storing terrain chunk: {:?} ({} bytes, {:?})", coord, field_data.len(), encoding);
+    
+    let noise_data = decode_field(&field_data, encoding, CHUNK_VOXEL_COUNT)
+        .map_err(|e| format!("Terrain chunk {:?}: {}", coord, e))?;
+    let materials = material_data
+        .map(|data| decode_materials(&data, CHUNK_VOXEL_COUNT))
+        .transpose()
+        .map_err(|e| format!("Terrain chunk {:?}: {}", coord, e))?;
+    
+    let planet = find_planet(ctx, coord.planet_id)?;
+    let chunk_id = coord.chunk_id()?;
+    let existing = ctx.db.terrain_chunk().chunk_id().find(chunk_id);
+    
+    // Optimistic concurrency: the write must be based on the current version
+    check_chunk_version(&coord, existing.as_ref(), base_version)?;
+    
+    // Only keep the samples that differ from the server-generated base by more than
+    // the upload's quantization error; the base is clamped and quantized like the upload
+    // so unmodified samples outside the quantized range are not stored
+    let generator = planet.generator();
+    let origin = chunk_origin(&coord);
+    let base: Vec<f32> = generate_base_field(&generator, &origin)
+        .into_iter()
+        .map(|value| encoded_value(value, encoding))
+        .collect();
+    let deltas = ChunkDeltas {
+        densities: diff_against_base(&base, &noise_data, encoding_precision(encoding)),
+        materials: match materials {
+            Some(materials) => diff_materials(&generate_base_materials(&generator, &origin), &materials),
+            None => match &existing {
+                Some(chunk) => chunk.deltas()?.materials,
+                None => Default::default(),
+            },
+        },
+    };
+    
+    // Changed materials must come from the planet's palette
+    if let Some((&index, &material)) = deltas.materials.iter().find(|(_, &material)| material as usize >= planet.materials.len()) {
+        return Err(format!(
+            "Terrain chunk {:?}: sample {} has material {}, not in the palette of planet {} ({} materials)",
+            coord,
+            index,
+            material,
+            planet.name,
+            planet.materials.len()
+        ));
+    }
     
     // Check if chunk already exists
-    if let Some(existing_chunk) = ctx.db.terrain_chunk().find(|c| c.chunk_key == chunk_key) {
+    let previous = match &existing {
+        Some(chunk) => chunk.deltas()?,
+        None => ChunkDeltas::default(),
+    };
+    let voxels_modified = changed_samples(&previous, &deltas);
+    let chunk_version = if let Some(chunk) = existing {
         // Update existing chunk
-        let mut updated_chunk = existing_chunk.clone();
-        updated_chunk.noise_data = noise_data;
-        updated_chunk.last_accessed = ctx.timestamp;
-        ctx.db.terrain_chunk().update(&existing_chunk, updated_chunk)?;
-        spacetimedb::log::info!("[TERRAIN] Updated existing chunk: {}", chunk_key);
+        let version = write_chunk_deltas(ctx, chunk, &deltas, &generator, true)?;
+        spacetimedb::log::info!("[TERRAIN] Updated existing chunk: {:?} ({} modified voxels)", coord, deltas.densities.len());
+        version
     } else {
         // Create new chunk
-        ctx.db.terrain_chunk().insert(TerrainChunk {
-            chunk_key: chunk_key.clone(),
-            planet_type,
-            chunk_x,
-            chunk_y,
-            chunk_z,
-            noise_data,
-            created_at: ctx.timestamp,
-            last_accessed: ctx.timestamp,
-        })?;
-        spacetimedb::log::info!("[TERRAIN] Created new chunk: {}", chunk_key);
+        let chunk = new_terrain_chunk(ctx, &coord, chunk_id);
+        let version = write_chunk_deltas(ctx, chunk, &deltas, &generator, false)?;
+        spacetimedb::log::info!("[TERRAIN] Created new chunk: {:?} ({} modified voxels)", coord, deltas.densities.len());
+        version
+    };
+    log_terrain_edit(ctx, chunk_id, coord.planet_id, TerrainEditAction::Upload(encoding), voxels_modified, chunk_version);
+    
+    // Neighbors duplicate this chunk's border samples; the stored field wins so they match
+    for neighbor in neighbor_coords(&coord) {
+        let Ok((neighbor_chunk, stored)) = load_terrain_chunk(ctx, &neighbor) else {
+            continue;
+        };
+        let original = neighbor_chunk.deltas()?;
+        let mut neighbor_deltas = original.clone();
+        if copy_shared_samples(&deltas, &mut neighbor_deltas, &shared_samples(&coord, &neighbor)) {
+            let neighbor_id = neighbor_chunk.chunk_id;
+            let version = write_chunk_deltas(ctx, neighbor_chunk, &neighbor_deltas, &generator, stored)?;
+            let synced = changed_samples(&original, &neighbor_deltas);
+            log_terrain_edit(ctx, neighbor_id, neighbor.planet_id, TerrainEditAction::Upload(encoding), synced, version);
+            spacetimedb::log::info!("[TERRAIN] Synced border samples of neighbor chunk: {:?}", neighbor);
+        }
     }
     
     Ok(())
 }
 
+// Stored chunk at coord, or a fresh unmodified one; the flag tells whether it is stored
+// Rejects a write based on another version than the chunk's current one (0 = never written)
+fn check_chunk_version(coord: &ChunkCoord, chunk: Option<&TerrainChunk>, base_version: u64) -> Result<(), String> {
+    let current_version = chunk.map_or(0, |chunk| chunk.version);
+    if base_version != current_version {
+        return Err(format!(
+            "Version conflict on terrain chunk {:?}: write is based on version {} but the chunk is at version {}; \
+             fetch the chunk again and rebase local edits",
+            coord,
+            base_version,
+            current_version
+        ));
+    }
+    Ok(())
+}
+
+fn load_terrain_chunk(ctx: &ReducerContext, coord: &ChunkCoord) -> Result<(TerrainChunk, bool), String> {
+    let chunk_id = coord.chunk_id()?;
+    Ok(match ctx.db.terrain_chunk().chunk_id().find(chunk_id) {
+        Some(chunk) => (chunk, true),
+        None => (new_terrain_chunk(ctx, coord, chunk_id), false),
+    })
+}
+
+// Saves new deltas as the chunk's next version and returns that version
+fn write_chunk_deltas(
+    ctx: &ReducerContext,
+    mut chunk: TerrainChunk,
+    deltas: &ChunkDeltas,
+    generator: &TerrainGenerator,
+    stored: bool,
+) -> Result<u64, String> {
+    chunk.set_deltas(deltas);
+    chunk.version += 1;
+    chunk.last_accessed = ctx.timestamp;
+    let version = chunk.version;
+    
+    if stored {
+        refresh_materialized_chunk(ctx, &chunk, generator)?;
+        ctx.db.terrain_chunk().chunk_id().update(chunk);
+    } else {
+        ctx.db.terrain_chunk().insert(chunk);
+    }
+    Ok(version)
+}
+
+// Unmodified chunks store no voxels; the server generates their field on demand
+fn new_terrain_chunk(ctx: &ReducerContext, coord: &ChunkCoord, chunk_id: u64) -> TerrainChunk {
+    TerrainChunk {
+        chunk_id,
+        planet_id: coord.planet_id,
+        chunk_x: coord.x,
+        chunk_y: coord.y,
+        chunk_z: coord.z,
+        delta_encoding: STORED_DELTA_ENCODING,
+        delta_data: Vec::new(),
+        modified_voxels: 0,
+        material_data: Vec::new(),
+        modified_materials: 0,
+        version: 0,
+        created_at: ctx.timestamp,
+        last_accessed: ctx.timestamp,
+    }
+}
+
 #[spacetimedb::reducer]
-pub fn get_terrain_chunk(ctx: &ReducerContext, chunk_key: String) -> Result<(), String> {
-    spacetimedb::log::info!("[TERRAIN] Retrieving terrain chunk: {}", chunk_key);
+pub fn get_terrain_chunk(ctx: &ReducerContext, coord: ChunkCoord, encoding: ChunkEncoding) -> Result<(), String> {
+    spacetimedb::log::info!("[TERRAIN] Retrieving terrain chunk: {:?}", coord);
+    
+    let planet = find_planet(ctx, coord.planet_id)?;
+    let chunk_id = coord.chunk_id()?;
     
-    if let Some(mut chunk) = ctx.db.terrain_chunk().find(|c| c.chunk_key == chunk_key) {
-        // Update last accessed time
-        chunk.last_accessed = ctx.timestamp;
-        ctx.db.terrain_chunk().update(&chunk, chunk.clone())?;
-        spacetimedb::log::info!("[TERRAIN] Found and updated access time for chunk: {}", chunk_key);
+    // Chunks nobody has touched yet are generated on demand
+    let chunk = match ctx.db.terrain_chunk().chunk_id().find(chunk_id) {
+        Some(mut chunk) => {
+            // Update last accessed time
+            chunk.last_accessed = ctx.timestamp;
+            ctx.db.terrain_chunk().chunk_id().update(chunk)
+        }
+        None => {
+            spacetimedb::log::info!("[TERRAIN] Generating missing chunk: {:?}", coord);
+            ctx.db.terrain_chunk().insert(new_terrain_chunk(ctx, &coord, chunk_id))
+        }
+    };
+    
+    let (densities, materials) = chunk.materialize(&planet.generator())?;
+    let response = ChunkRequest {
+        requester: ctx.sender,
+        chunk_id,
+        coord,
+        version: chunk.version,
+        encoding,
+        field_data: encode_field(&densities, encoding),
+        material_data: encode_materials(&materials),
+        requested_at: ctx.timestamp,
+    };
+    
+    if ctx.db.chunk_request().requester().find(ctx.sender).is_some() {
+        ctx.db.chunk_request().requester().update(response);
     } else {
-        spacetimedb::log::info!("[TERRAIN] Chunk not found: {}", chunk_key);
+        ctx.db.chunk_request().insert(response);
     }
     
     Ok(())
@@ -253,10 +1100,12 @@ pub fn get_terrain_chunk(ctx: &ReducerContext, chunk_key: String) -> Result<(),
 #[spacetimedb::reducer]
 pub fn store_initial_chunks_for_planet(
     ctx: &ReducerContext,
-    planet_type: String,
+    planet_id: u32,
     radius: i32,
 ) -> Result<(), String> {
-    spacetimedb::log::info!("[TERRAIN] Storing initial chunks for planet type: {} with radius: {}", planet_type, radius);
+    spacetimedb::log::info!("[TERRAIN] Storing initial chunks for planet: {} with radius: {}", planet_id, radius);
+    
+    find_planet(ctx, planet_id)?;
     
     let mut chunks_created = 0;
     
@@ -264,61 +1113,1155 @@ pub fn store_initial_chunks_for_planet(
     for x in -radius..=radius {
         for y in -1..=0 { // Two vertical layers as per the pattern
             for z in -radius..=radius {
-                let chunk_key = format!("{},{},{}_{}", x, y, z, planet_type);
+                let coord = ChunkCoord { planet_id, x, y, z };
+                let chunk_id = coord.chunk_id()?;
                 
                 // Check if chunk already exists
-                if ctx.db.terrain_chunk().find(|c| c.chunk_key == chunk_key).is_none() {
-                    // Create placeholder chunk (noise data will be generated client-side)
-                    let placeholder_noise = vec![0.0; (33 * 33 * 33) as usize]; // CHUNK_SIZE+1 cubed
-                    
-                    ctx.db.terrain_chunk().insert(TerrainChunk {
-                        chunk_key: chunk_key.clone(),
-                        planet_type: planet_type.clone(),
-                        chunk_x: x,
-                        chunk_y: y,
-                        chunk_z: z,
-                        noise_data: placeholder_noise,
-                        created_at: ctx.timestamp,
-                        last_accessed: ctx.timestamp,
-                    })?;
-                    
+                if ctx.db.terrain_chunk().chunk_id().find(chunk_id).is_none() {
+                    ctx.db.terrain_chunk().insert(new_terrain_chunk(ctx, &coord, chunk_id));
                     chunks_created += 1;
                 }
             }
         }
     }
     
-    spacetimedb::log::info!("[TERRAIN] Created {} initial chunks for planet type: {}", chunks_created, planet_type);
+    spacetimedb::log::info!("[TERRAIN] Created {} initial chunks for planet: {}", chunks_created, planet_id);
     Ok(())
 }
 
-// --- Player Management Reducers ---
-
 #[spacetimedb::reducer]
-pub fn register_player(ctx: &ReducerContext, username: String) -> Result<(), String> {
-    let identity = ctx.sender;
-    spacetimedb::log::info!("[REGISTER] Registering player: {} with identity: {}", username, identity.to_hex());
+pub fn materialize_terrain_chunk(ctx: &ReducerContext, coord: ChunkCoord, encoding: ChunkEncoding) -> Result<(), String> {
+    let chunk_id = coord.chunk_id()?;
+    let mut chunk = ctx.db.terrain_chunk()
+        .chunk_id()
+        .find(chunk_id)
+        .ok_or_else(|| format!("Terrain chunk {:?} not found", coord))?;
+    let planet = find_planet(ctx, chunk.planet_id)?;
     
-    // Check if player already exists
-    if ctx.db.player().find(|p| p.identity == identity).is_some() {
-        return Err(format!("Player with identity {} already registered", identity.to_hex()));
-    }
+    let (densities, materials) = chunk.materialize(&planet.generator())?;
+    let materialized = MaterializedChunk {
+        chunk_id,
+        encoding,
+        field_data: encode_field(&densities, encoding),
+        material_data: encode_materials(&materials),
+        materialized_at: ctx.timestamp,
+    };
     
-    // Check if username is already taken
-    if ctx.db.player().find(|p| p.username == username).is_some() {
-        return Err(format!("Username '{}' is already taken", username));
+    if ctx.db.materialized_chunk().chunk_id().find(chunk_id).is_some() {
+        ctx.db.materialized_chunk().chunk_id().update(materialized);
+    } else {
+        ctx.db.materialized_chunk().insert(materialized);
     }
     
-    // Create new player
-    let new_player = PlayerData {
-        identity,
-        username: username.clone(),
-        position: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
-        rotation: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+    chunk.last_accessed = ctx.timestamp;
+    ctx.db.terrain_chunk().chunk_id().update(chunk);
+    
+    spacetimedb::log::info!("[TERRAIN] Materialized chunk: {:?}", coord);
+    Ok(())
+}
+
+// Keeps an already materialized field in step with a chunk's deltas
+fn refresh_materialized_chunk(ctx: &ReducerContext, chunk: &TerrainChunk, generator: &TerrainGenerator) -> Result<(), String> {
+    if let Some(mut materialized) = ctx.db.materialized_chunk().chunk_id().find(chunk.chunk_id) {
+        let (densities, materials) = chunk.materialize(generator)?;
+        materialized.field_data = encode_field(&densities, materialized.encoding);
+        materialized.material_data = encode_materials(&materials);
+        materialized.materialized_at = ctx.timestamp;
+        ctx.db.materialized_chunk().chunk_id().update(materialized);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_terrain_edit(
+    ctx: &ReducerContext,
+    planet_id: u32,
+    center: Vector3,
+    shape: BrushShape,
+    mode: EditMode,
+    strength: f32,
+    radius: f32,
+    material_id: u8, // Palette index for terrain added by an Add brush; ignored by Subtract
+    base_version: Option<u64>, // Version of the chunk containing center the edit was made against; None skips the check
+) -> Result<(), String> {
+    let identity = ctx.sender;
+
+    // Validate brush parameters
+    if !(radius > 0.0 && radius <= MAX_BRUSH_RADIUS) {
+        return Err(format!("Brush radius {} must be in (0, {}]", radius, MAX_BRUSH_RADIUS));
+    }
+    if !(strength > 0.0 && strength <= MAX_BRUSH_STRENGTH) {
+        return Err(format!("Brush strength {} must be in (0, {}]", strength, MAX_BRUSH_STRENGTH));
+    }
+
+    // Only registered players may edit terrain, on their own planet and within reach
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or_else(|| format!("Player with identity {} not found", identity.to_hex()))?;
+    if player.planet_id != Some(planet_id) {
+        return Err(format!("Player {} is not on planet {}", player.username, planet_id));
+    }
+    let planet = find_planet(ctx, planet_id)?;
+
+    // Reach is measured from the server's copy of the player position, not the client's
+    let distance = calculate_distance(&player.position, &center);
+    if distance.is_nan() || distance > MINING_REACH {
+        return Err(format!("Edit is {:.1} away, out of reach {}", distance, MINING_REACH));
+    }
+
+    if mode == EditMode::Add && material_id as usize >= planet.materials.len() {
+        return Err(format!(
+            "Material {} is not in the palette of planet {} ({} materials)",
+            material_id,
+            planet.name,
+            planet.materials.len()
+        ));
+    }
+
+    // Brushes are relative, so concurrent edits never overwrite each other; clients that
+    // predicted the result locally name the version they edited to learn when to rebase
+    if let Some(base_version) = base_version {
+        let (coord, _, _) = nearest_sample(planet_id, &center);
+        let chunk = ctx.db.terrain_chunk().chunk_id().find(coord.chunk_id()?);
+        check_chunk_version(&coord, chunk.as_ref(), base_version)?;
+    }
+
+    let brush = Brush { center: center.clone(), shape, mode, strength, radius, material: material_id };
+    let (chunks_modified, total_voxels, changes) = edit_terrain(ctx, &planet, &brush)?;
+
+    // Placed terrain is paid for from the inventory and refunded when carved away;
+    // a shortfall or a full inventory rolls back the whole edit
+    let cost = material_items(&planet, &changes.placed)?;
+    consume_items(ctx, identity, &cost.iter().map(|(item_id, quantity)| (item_id.as_str(), *quantity)).collect::<Vec<_>>())?;
+    for (item_id, quantity) in material_items(&planet, &changes.removed)? {
+        credit_item(ctx, identity, &item_id, quantity)?;
+    }
+
+    spacetimedb::log::info!(
+        "[TERRAIN] Applied {:?} {:?} edit at ({:.1}, {:.1}, {:.1}) to {} chunks ({} voxels) by {}",
+        mode,
+        shape,
+        center.x,
+        center.y,
+        center.z,
+        chunks_modified,
+        total_voxels,
+        identity.to_hex()
+    );
+
+    Ok(())
+}
+
+// Applies a validated brush for the sender and returns (chunks modified, voxels modified,
+// placed material added and removed, generated material mined)
+fn edit_terrain(ctx: &ReducerContext, planet: &Planet, brush: &Brush) -> Result<(u32, u32, MaterialChanges), String> {
+    let generator = planet.generator();
+    let mut changes = MaterialChanges::default();
+
+    // Apply the brush to the authoritative voxel deltas of every chunk it reaches,
+    // creating chunks that were not stored yet
+    let mut edited = Vec::new();
+    for coord in chunks_touched_by_brush(planet.planet_id, brush) {
+        let (chunk, stored) = load_terrain_chunk(ctx, &coord)?;
+        let original = chunk.deltas()?;
+        let mut deltas = original.clone();
+        let voxels_modified = apply_brush(&mut deltas, &chunk_origin(&coord), brush, &generator, &mut changes);
+        edited.push((chunk, stored, original, deltas, voxels_modified));
+    }
+
+    // Border samples are duplicated between neighbors; earlier chunks win so all copies match
+    for source in 0..edited.len() {
+        let (before, after) = edited.split_at_mut(source + 1);
+        let (source_chunk, _, _, source_deltas, _) = &before[source];
+        for (target_chunk, _, _, target_deltas, _) in after.iter_mut() {
+            let shared = shared_samples(&source_chunk.coord(), &target_chunk.coord());
+            copy_shared_samples(source_deltas, target_deltas, &shared);
+        }
+    }
+
+    // The reducer is one transaction, so all affected chunks change together or not at all
+    let mut chunks_modified = 0;
+    let mut total_voxels = 0;
+    for (chunk, stored, original, deltas, voxels_modified) in edited {
+        if voxels_modified == 0 && deltas == original {
+            continue;
+        }
+
+        let chunk_id = chunk.chunk_id;
+        let chunk_version = write_chunk_deltas(ctx, chunk, &deltas, &generator, stored)?;
+
+        // Record the edit; clients subscribed to terrain_edit see it alongside the chunk update
+        let action = TerrainEditAction::Brush(BrushStroke {
+            center: brush.center.clone(),
+            shape: brush.shape,
+            mode: brush.mode,
+            strength: brush.strength,
+            radius: brush.radius,
+            material_id: brush.material,
+        });
+        log_terrain_edit(ctx, chunk_id, planet.planet_id, action, voxels_modified, chunk_version);
+
+        chunks_modified += 1;
+        total_voxels += voxels_modified;
+    }
+
+    Ok((chunks_modified, total_voxels, changes))
+}
+
+// Inventory items matching per-palette-index sample counts
+fn material_items(planet: &Planet, counts: &BTreeMap<u8, u32>) -> Result<Vec<(String, u32)>, String> {
+    counts
+        .iter()
+        .map(|(&material_id, &quantity)| {
+            planet.materials
+                .get(material_id as usize)
+                .map(|material| (material.symbol.clone(), quantity))
+                .ok_or_else(|| format!("Planet {} has no material {}", planet.name, material_id))
+        })
+        .collect()
+}
+
+fn log_terrain_edit(
+    ctx: &ReducerContext,
+    chunk_id: u64,
+    planet_id: u32,
+    action: TerrainEditAction,
+    voxels_modified: u32,
+    chunk_version: u64,
+) {
+    ctx.db.terrain_edit().insert(TerrainEdit {
+        edit_id: 0,
+        chunk_id,
+        planet_id,
+        sender: ctx.sender,
+        action,
+        voxels_modified,
+        chunk_version,
+        applied_at: ctx.timestamp,
+    });
+}
+
+// Reads authoritative terrain samples, decoding each chunk's deltas and
+// evaluating each grid density once
+struct TerrainSampler<'a> {
+    ctx: &'a ReducerContext,
+    planet_id: u32,
+    generator: TerrainGenerator,
+    chunks: BTreeMap<u64, ChunkDeltas>,
+    grid: BTreeMap<(i32, i32, i32), f32>,
+}
+
+impl<'a> TerrainSampler<'a> {
+    fn new(ctx: &'a ReducerContext, planet: &Planet) -> Self {
+        TerrainSampler {
+            ctx,
+            planet_id: planet.planet_id,
+            generator: planet.generator(),
+            chunks: BTreeMap::new(),
+            grid: BTreeMap::new(),
+        }
+    }
+
+    // Decodes a chunk's deltas into the cache and returns its id
+    fn load_chunk(&mut self, coord: &ChunkCoord) -> Result<u64, String> {
+        let chunk_id = coord.chunk_id()?;
+        if !self.chunks.contains_key(&chunk_id) {
+            let deltas = match self.ctx.db.terrain_chunk().chunk_id().find(chunk_id) {
+                Some(chunk) => chunk.deltas()?,
+                None => ChunkDeltas::default(),
+            };
+            self.chunks.insert(chunk_id, deltas);
+        }
+        Ok(chunk_id)
+    }
+
+    // Density and material of the sample nearest to position
+    fn sample(&mut self, position: &Vector3) -> Result<(f32, u8), String> {
+        let (coord, index, sample) = nearest_sample(self.planet_id, position);
+        let chunk_id = self.load_chunk(&coord)?;
+        let deltas = &self.chunks[&chunk_id];
+        let density = deltas.densities.get(&index).copied()
+            .unwrap_or_else(|| self.generator.density(&sample));
+        let material = deltas.materials.get(&index).copied()
+            .unwrap_or_else(|| self.generator.material(&sample));
+        Ok((density, material))
+    }
+
+    fn grid_density(&mut self, x: i32, y: i32, z: i32) -> Result<f32, String> {
+        if let Some(&density) = self.grid.get(&(x, y, z)) {
+            return Ok(density);
+        }
+        let (density, _) = self.sample(&Vector3 { x: x as f32, y: y as f32, z: z as f32 })?;
+        self.grid.insert((x, y, z), density);
+        Ok(density)
+    }
+
+    // Density at any position, interpolated between the surrounding samples
+    fn density(&mut self, position: &Vector3) -> Result<f32, String> {
+        let (x, y, z) = (position.x.floor(), position.y.floor(), position.z.floor());
+        let mut corners = [0.0; 8];
+        for (corner, value) in corners.iter_mut().enumerate() {
+            *value = self.grid_density(
+                x as i32 + (corner & 1) as i32,
+                y as i32 + (corner >> 1 & 1) as i32,
+                z as i32 + (corner >> 2) as i32,
+            )?;
+        }
+        Ok(trilinear(&corners, &Vector3 { x: position.x - x, y: position.y - y, z: position.z - z }))
+    }
+
+    fn is_solid(&mut self, position: &Vector3) -> Result<bool, String> {
+        Ok(self.density(position)? < SURFACE_LEVEL)
+    }
+}
+
+// --- Terrain Storage Management ---
+
+#[spacetimedb::reducer]
+pub fn cleanup_terrain_chunks(ctx: &ReducerContext, _arg: ChunkCleanupSchedule) -> Result<(), String> {
+    // Runs on schedule; admins may also trigger it manually
+    if ctx.sender != ctx.identity() {
+        require_admin(ctx)?;
+    }
+    
+    let config = ctx.db.terrain_storage_config()
+        .config_id()
+        .find(0)
+        .ok_or("Terrain storage config is missing")?;
+    let ttl = Duration::from_secs(config.chunk_ttl_secs);
+    
+    let planets: Vec<Planet> = ctx.db.planet().iter().collect();
+    for planet in planets {
+        let chunks: Vec<TerrainChunk> = ctx.db.terrain_chunk().planet_chunk().filter(planet.planet_id).collect();
+        let mut stored_chunks = chunks.len() as u32;
+        
+        // Modified chunks hold player edits, written chunks keep their version so it stays
+        // monotonic, and chunks in someone's view are in use; everything else regenerates
+        // deterministically and may be evicted
+        let mut candidates: Vec<&TerrainChunk> = chunks.iter()
+            .filter(|chunk| !chunk.is_modified() && chunk.version == 0)
+            .filter(|chunk| ctx.db.chunk_interest().chunk_id().filter(chunk.chunk_id).next().is_none())
+            .collect();
+        candidates.sort_by_key(|chunk| chunk.last_accessed);
+        
+        let mut evicted = 0;
+        for chunk in candidates {
+            let expired = ctx.timestamp
+                .duration_since(chunk.last_accessed)
+                .is_some_and(|idle| idle >= ttl);
+            if !expired && stored_chunks <= planet.max_stored_chunks {
+                break;
+            }
+            
+            ctx.db.terrain_chunk().chunk_id().delete(chunk.chunk_id);
+            ctx.db.materialized_chunk().chunk_id().delete(chunk.chunk_id);
+            stored_chunks -= 1;
+            evicted += 1;
+        }
+        
+        // Idle modified chunks are archived to their sparse deltas only
+        for chunk in chunks.iter().filter(|chunk| chunk.is_modified()) {
+            let idle = ctx.timestamp.duration_since(chunk.last_accessed).is_some_and(|idle| idle >= ttl);
+            if idle {
+                ctx.db.materialized_chunk().chunk_id().delete(chunk.chunk_id);
+            }
+        }
+        
+        if stored_chunks > planet.max_stored_chunks {
+            spacetimedb::log::warn!(
+                "[STORAGE] Planet {} stores {} chunks, above its quota of {}; remaining chunks are modified or in view",
+                planet.name,
+                stored_chunks,
+                planet.max_stored_chunks
+            );
+        }
+        
+        update_terrain_stats(ctx, &planet, &chunks, stored_chunks, evicted);
+        if evicted > 0 {
+            spacetimedb::log::info!("[STORAGE] Evicted {} unmodified chunks from planet {}", evicted, planet.name);
+        }
+    }
+    
+    Ok(())
+}
+
+fn update_terrain_stats(ctx: &ReducerContext, planet: &Planet, chunks: &[TerrainChunk], stored_chunks: u32, evicted: u32) {
+    // Evicted chunks are never modified, so the modified totals cover all of `chunks`
+    let modified: Vec<&TerrainChunk> = chunks.iter().filter(|chunk| chunk.is_modified()).collect();
+    let previous_total = ctx.db.planet_terrain_stats()
+        .planet_id()
+        .find(planet.planet_id)
+        .map_or(0, |stats| stats.evicted_total);
+    
+    let stats = PlanetTerrainStats {
+        planet_id: planet.planet_id,
+        stored_chunks,
+        modified_chunks: modified.len() as u32,
+        modified_voxels: modified.iter().map(|chunk| chunk.modified_voxels as u64).sum(),
+        evicted_last_cleanup: evicted,
+        evicted_total: previous_total + evicted as u64,
+        last_cleanup: ctx.timestamp,
+    };
+    
+    if ctx.db.planet_terrain_stats().planet_id().find(planet.planet_id).is_some() {
+        ctx.db.planet_terrain_stats().planet_id().update(stats);
+    } else {
+        ctx.db.planet_terrain_stats().insert(stats);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn set_terrain_storage_config(
+    ctx: &ReducerContext,
+    chunk_ttl_secs: u64,
+    cleanup_interval_secs: u64,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    
+    if cleanup_interval_secs == 0 {
+        return Err("Cleanup interval must be at least one second".to_string());
+    }
+    
+    let config = TerrainStorageConfig {
+        config_id: 0,
+        chunk_ttl_secs,
+        cleanup_interval_secs,
+        updated_at: ctx.timestamp,
+    };
+    if ctx.db.terrain_storage_config().config_id().find(0).is_some() {
+        ctx.db.terrain_storage_config().config_id().update(config);
+    } else {
+        ctx.db.terrain_storage_config().insert(config);
+    }
+    
+    // Reschedule the cleanup with the new interval
+    let schedule_ids: Vec<u64> = ctx.db.chunk_cleanup_schedule().iter().map(|s| s.scheduled_id).collect();
+    for scheduled_id in schedule_ids {
+        ctx.db.chunk_cleanup_schedule().scheduled_id().delete(scheduled_id);
+    }
+    ctx.db.chunk_cleanup_schedule().insert(ChunkCleanupSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(cleanup_interval_secs).into()),
+    });
+    
+    spacetimedb::log::info!(
+        "[STORAGE] Chunk TTL set to {}s, cleanup every {}s",
+        chunk_ttl_secs,
+        cleanup_interval_secs
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_planet_chunk_quota(ctx: &ReducerContext, planet_id: u32, max_stored_chunks: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    
+    let mut planet = find_planet(ctx, planet_id)?;
+    planet.max_stored_chunks = max_stored_chunks;
+    planet.updated_at = ctx.timestamp;
+    
+    spacetimedb::log::info!("[STORAGE] Planet {} chunk quota set to {}", planet.name, max_stored_chunks);
+    ctx.db.planet().planet_id().update(planet);
+    
+    Ok(())
+}
+
+// --- Mining ---
+
+// Mines with the given tool, or bare hands when tool_id is None
+#[spacetimedb::reducer]
+pub fn mine_terrain(ctx: &ReducerContext, tool_id: Option<u64>, target: Vector3) -> Result<(), String> {
+    let identity = ctx.sender;
+
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or_else(|| format!("Player with identity {} not found", identity.to_hex()))?;
+    let planet_id = player.planet_id
+        .ok_or_else(|| format!("Player {} is not on a planet", player.username))?;
+    let planet = find_planet(ctx, planet_id)?;
+
+    let tool = tool_id.map(|tool_id| find_owned_tool(ctx, identity, tool_id)).transpose()?;
+    if let Some(tool) = &tool {
+        if tool.durability == 0 {
+            return Err(format!("{:?} {} is broken and needs repair", tool.tool_type, tool.tool_id));
+        }
+    }
+    let tool_type = tool.as_ref().map_or(ToolType::Hand, |tool| tool.tool_type);
+
+    // Reach is measured from the server's copy of the player position, not the client's
+    let distance = calculate_distance(&player.position, &target);
+    if distance.is_nan() || distance > MINING_REACH {
+        return Err(format!("Target is {:.1} away, out of mining reach {}", distance, MINING_REACH));
+    }
+
+    // Look at the sample nearest to the target in the authoritative chunk data
+    let mut terrain = TerrainSampler::new(ctx, &planet);
+    let (density, material_id) = terrain.sample(&target)?;
+    if density >= SURFACE_LEVEL {
+        return Err("Nothing to mine at target".to_string());
+    }
+
+    let symbol = planet.materials.get(material_id as usize)
+        .map(|material| material.symbol.clone())
+        .ok_or_else(|| format!("Planet {} has no material {}", planet.name, material_id))?;
+
+    let stats = tool_stats(tool_type);
+    let hardness = material_hardness(&symbol);
+    if stats.power < hardness {
+        return Err(format!("{:?} (power {}) is too weak to mine {} (hardness {})", tool_type, stats.power, symbol, hardness));
+    }
+
+    // Carve the terrain, then credit the yield; a full inventory rolls back the whole edit.
+    // Only generated samples carved from solid to air yield resources, and only materials the
+    // tool can mine; placed terrain is refunded as it was paid for, so placing and mining
+    // material again never creates more of it
+    let brush = Brush {
+        center: target.clone(),
+        shape: BrushShape::Sphere,
+        mode: EditMode::Subtract,
+        strength: (stats.speed as f32 * MINING_STRENGTH_PER_SPEED).min(MAX_BRUSH_STRENGTH),
+        radius: MINING_RADIUS,
+        material: material_id,
+    };
+    let (chunks_modified, voxels_modified, changes) = edit_terrain(ctx, &planet, &brush)?;
+
+    let mut amount = 0;
+    for (item_id, samples) in material_items(&planet, &changes.mined)? {
+        if material_hardness(&item_id) > stats.power {
+            continue;
+        }
+        let quantity = mining_yield(&item_id, &stats) * samples;
+        credit_item(ctx, identity, &item_id, quantity)?;
+        amount += quantity;
+    }
+    for (item_id, quantity) in material_items(&planet, &changes.removed)? {
+        credit_item(ctx, identity, &item_id, quantity)?;
+    }
+
+    if let Some(mut tool) = tool {
+        tool.durability = tool.durability.saturating_sub(tool_damage(&symbol));
+        if tool.durability == 0 {
+            spacetimedb::log::info!("[MINING] {}'s {:?} {} broke", player.username, tool.tool_type, tool.tool_id);
+        }
+        ctx.db.tool().tool_id().update(tool);
+    }
+
+    spacetimedb::log::info!(
+        "[MINING] {} mined {} for {} resources with {:?} at ({:.1}, {:.1}, {:.1}) ({} chunks, {} voxels)",
+        player.username,
+        symbol,
+        amount,
+        tool_type,
+        target.x,
+        target.y,
+        target.z,
+        chunks_modified,
+        voxels_modified
+    );
+
+    Ok(())
+}
+
+// --- Building ---
+
+#[spacetimedb::reducer]
+pub fn place_structure(ctx: &ReducerContext, prefab: PrefabType, position: Vector3, rotation: Vector3) -> Result<(), String> {
+    let owner = ctx.sender;
+    let player = ctx.db.player().identity().find(owner)
+        .ok_or_else(|| format!("Player with identity {} not found", owner.to_hex()))?;
+    let planet_id = player.planet_id
+        .ok_or_else(|| format!("Player {} is not on a planet", player.username))?;
+    let planet = find_planet(ctx, planet_id)?;
+
+    let distance = calculate_distance(&player.position, &position);
+    if distance.is_nan() || distance > BUILD_REACH {
+        return Err(format!("Position is {:.1} away, out of building reach {}", distance, BUILD_REACH));
+    }
+    if !rotation.y.is_finite() {
+        return Err("Structure rotation must be finite".to_string());
+    }
+
+    let bounds = structure_bounds(prefab, &position, &rotation);
+    let center = world_to_chunk(planet_id, &position);
+    let nearby_chunks = chunks_in_view(&center, 1)
+        .iter()
+        .map(|coord| coord.chunk_id())
+        .collect::<Result<Vec<u64>, String>>()?;
+
+    // Structures may touch each other but not overlap
+    let mut touches_structure = false;
+    for chunk_id in &nearby_chunks {
+        for other in ctx.db.structure().chunk_id().filter(chunk_id) {
+            let other_bounds = structure_bounds(other.prefab, &other.position, &other.rotation);
+            if bounds.overlaps(&other_bounds, -STRUCTURE_CONTACT_MARGIN) {
+                return Err(format!("Overlaps {:?} {}", other.prefab, other.structure_id));
+            }
+            touches_structure |= bounds.overlaps(&other_bounds, STRUCTURE_CONTACT_MARGIN);
+        }
+
+        if ctx.db.player().chunk_id().filter(chunk_id).any(|other| bounds.overlaps(&player_bounds(&other.position), 0.0)) {
+            return Err("A player is standing in the way".to_string());
+        }
+    }
+
+    let mut terrain = TerrainSampler::new(ctx, &planet);
+    if !is_clear_of_terrain(&mut terrain, &bounds)? {
+        return Err("Structure would intersect terrain".to_string());
+    }
+    if !touches_structure && !rests_on_terrain(&mut terrain, &bounds)? {
+        return Err("Structure must rest on terrain or touch another structure".to_string());
+    }
+
+    let max_health = prefab_max_health(prefab);
+    let structure = ctx.db.structure().insert(Structure {
+        structure_id: 0,
+        owner,
+        prefab,
+        planet_id,
+        chunk_id: center.chunk_id()?,
+        position: position.clone(),
+        rotation,
+        health: max_health,
+        max_health,
+        placed_at: ctx.timestamp,
+    });
+
+    spacetimedb::log::info!(
+        "[BUILDING] {} placed {:?} {} at ({:.1}, {:.1}, {:.1})",
+        player.username,
+        prefab,
+        structure.structure_id,
+        position.x,
+        position.y,
+        position.z
+    );
+    Ok(())
+}
+
+fn is_clear_of_terrain(terrain: &mut TerrainSampler, bounds: &Bounds) -> Result<bool, String> {
+    for point in bounds.shrink(STRUCTURE_TERRAIN_TOLERANCE).sample_points() {
+        if terrain.is_solid(&point)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// Checks for solid terrain just below the bottom face
+fn rests_on_terrain(terrain: &mut TerrainSampler, bounds: &Bounds) -> Result<bool, String> {
+    let depth = 2.0 * STRUCTURE_TERRAIN_TOLERANCE;
+    let below = Bounds {
+        min: Vector3 { y: bounds.min.y - depth, ..bounds.min.clone() },
+        max: Vector3 { y: bounds.min.y - depth, ..bounds.max.clone() },
+    };
+    for point in below.sample_points() {
+        if terrain.is_solid(&point)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// Owners remove their own structures; admins may remove any
+#[spacetimedb::reducer]
+pub fn remove_structure(ctx: &ReducerContext, structure_id: u64) -> Result<(), String> {
+    let structure = ctx.db.structure().structure_id().find(structure_id)
+        .ok_or_else(|| format!("Structure {} not found", structure_id))?;
+    if structure.owner != ctx.sender {
+        require_admin(ctx)?;
+    }
+
+    ctx.db.structure().structure_id().delete(structure_id);
+    spacetimedb::log::info!("[BUILDING] {} removed {:?} {}", ctx.sender.to_hex(), structure.prefab, structure_id);
+    Ok(())
+}
+
+// --- Tools ---
+
+fn find_owned_tool(ctx: &ReducerContext, owner: Identity, tool_id: u64) -> Result<Tool, String> {
+    ctx.db.tool().tool_id().find(tool_id)
+        .filter(|tool| tool.owner == owner)
+        .ok_or_else(|| format!("Tool {} not found", tool_id))
+}
+
+fn new_tool(ctx: &ReducerContext, owner: Identity, tool_type: ToolType) -> Result<Tool, String> {
+    let max_durability = tool_stats(tool_type).max_durability
+        .ok_or_else(|| format!("{:?} is not a tool that can be owned", tool_type))?;
+    Ok(Tool {
+        tool_id: 0,
+        owner,
+        tool_type,
+        durability: max_durability,
+        max_durability,
+        created_at: ctx.timestamp,
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn grant_tool(ctx: &ReducerContext, owner: Identity, tool_type: ToolType) -> Result<(), String> {
+    require_admin(ctx)?;
+    let tool = ctx.db.tool().insert(new_tool(ctx, owner, tool_type)?);
+    spacetimedb::log::info!("[TOOLS] Granted {:?} {} to {}", tool_type, tool.tool_id, owner.to_hex());
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn repair_tool(ctx: &ReducerContext, tool_id: u64) -> Result<(), String> {
+    let mut tool = find_owned_tool(ctx, ctx.sender, tool_id)?;
+    if tool.durability >= tool.max_durability {
+        return Err(format!("{:?} {} is not damaged", tool.tool_type, tool_id));
+    }
+
+    consume_items(ctx, ctx.sender, repair_cost(tool.tool_type))?;
+    tool.durability = tool.max_durability;
+    spacetimedb::log::info!("[TOOLS] {} repaired {:?} {}", ctx.sender.to_hex(), tool.tool_type, tool_id);
+    ctx.db.tool().tool_id().update(tool);
+    Ok(())
+}
+
+// Upgrades a tool to the next tier at full durability; None crafts a first tool from bare hands
+#[spacetimedb::reducer]
+pub fn upgrade_tool(ctx: &ReducerContext, tool_id: Option<u64>) -> Result<(), String> {
+    let owner = ctx.sender;
+    let tool = tool_id.map(|tool_id| find_owned_tool(ctx, owner, tool_id)).transpose()?;
+    let tool_type = tool.as_ref().map_or(ToolType::Hand, |tool| tool.tool_type);
+    let (upgraded_type, cost) = upgrade_path(tool_type)
+        .ok_or_else(|| format!("{:?} is already the best tool", tool_type))?;
+
+    consume_items(ctx, owner, cost)?;
+    let upgraded = new_tool(ctx, owner, upgraded_type)?;
+    match tool {
+        Some(tool) => {
+            ctx.db.tool().tool_id().update(Tool { tool_id: tool.tool_id, created_at: tool.created_at, ..upgraded });
+        }
+        None => {
+            ctx.db.tool().insert(upgraded);
+        }
+    }
+
+    spacetimedb::log::info!("[TOOLS] {} upgraded {:?} to {:?}", owner.to_hex(), tool_type, upgraded_type);
+    Ok(())
+}
+
+// --- Crafting ---
+
+fn validate_stacks(stacks: &[ItemStack], what: &str) -> Result<(), String> {
+    if stacks.is_empty() {
+        return Err(format!("Recipe {} must not be empty", what));
+    }
+    if let Some(stack) = stacks.iter().find(|stack| stack.item_id.is_empty() || stack.quantity == 0) {
+        return Err(format!("Invalid recipe {} entry {:?}", what, stack));
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_recipe(
+    ctx: &ReducerContext,
+    recipe_id: String,
+    name: String,
+    inputs: Vec<ItemStack>,
+    outputs: Vec<ItemStack>,
+    craft_time_ms: u64,
+    required_station: Option<String>,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    if recipe_id.is_empty() {
+        return Err("Recipe id must not be empty".to_string());
+    }
+    validate_stacks(&inputs, "inputs")?;
+    validate_stacks(&outputs, "outputs")?;
+    if craft_time_ms > MAX_CRAFT_TIME_MS {
+        return Err(format!("Crafting time {}ms exceeds {}ms", craft_time_ms, MAX_CRAFT_TIME_MS));
+    }
+
+    let recipe = Recipe { recipe_id: recipe_id.clone(), name, inputs, outputs, craft_time_ms, required_station };
+    if ctx.db.recipe().recipe_id().find(&recipe_id).is_some() {
+        ctx.db.recipe().recipe_id().update(recipe);
+    } else {
+        ctx.db.recipe().insert(recipe);
+    }
+    spacetimedb::log::info!("[CRAFTING] Recipe {} set", recipe_id);
+    Ok(())
+}
+
+// Pending jobs keep their inputs and still complete with the recipe's outputs
+#[spacetimedb::reducer]
+pub fn remove_recipe(ctx: &ReducerContext, recipe_id: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    if !ctx.db.recipe().recipe_id().delete(&recipe_id) {
+        return Err(format!("Recipe {} not found", recipe_id));
+    }
+    spacetimedb::log::info!("[CRAFTING] Recipe {} removed", recipe_id);
+    Ok(())
+}
+
+// Consumes the recipe inputs now and schedules the outputs after the player's queued jobs
+#[spacetimedb::reducer]
+pub fn start_craft(ctx: &ReducerContext, recipe_id: String) -> Result<(), String> {
+    let owner = ctx.sender;
+    let player = ctx.db.player().identity().find(owner)
+        .ok_or_else(|| format!("Player with identity {} not found", owner.to_hex()))?;
+    let recipe = ctx.db.recipe().recipe_id().find(&recipe_id)
+        .ok_or_else(|| format!("Recipe {} not found", recipe_id))?;
+
+    let queued: Vec<CraftJob> = ctx.db.craft_job().owner().filter(owner).collect();
+    if queued.len() >= MAX_CRAFT_QUEUE {
+        return Err(format!("Craft queue is full ({} jobs)", MAX_CRAFT_QUEUE));
+    }
+
+    if let Some(station) = &recipe.required_station {
+        let has_station = ctx.db.inventory_item().owner().filter(owner).any(|item| &item.item_id == station);
+        if !has_station {
+            return Err(format!("Recipe {} requires a {}", recipe_id, station));
+        }
+    }
+
+    let cost: Vec<(&str, u32)> = recipe.inputs.iter().map(|stack| (stack.item_id.as_str(), stack.quantity)).collect();
+    consume_items(ctx, owner, &cost)?;
+
+    let starts_at = queued.iter().map(|job| job.completes_at).max().unwrap_or(ctx.timestamp).max(ctx.timestamp);
+    let completes_at = starts_at + Duration::from_millis(recipe.craft_time_ms);
+    ctx.db.craft_job().insert(CraftJob {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(completes_at),
+        owner,
+        recipe_id: recipe_id.clone(),
+        inputs: recipe.inputs.clone(),
+        started_at: ctx.timestamp,
+        completes_at,
+    });
+
+    spacetimedb::log::info!("[CRAFTING] {} started crafting {}", player.username, recipe_id);
+    Ok(())
+}
+
+// Cancels a pending craft and refunds its inputs; later jobs keep their completion times
+#[spacetimedb::reducer]
+pub fn cancel_craft(ctx: &ReducerContext, job_id: u64) -> Result<(), String> {
+    let owner = ctx.sender;
+    let job = ctx.db.craft_job().scheduled_id().find(job_id)
+        .filter(|job| job.owner == owner)
+        .ok_or_else(|| format!("Craft job {} not found", job_id))?;
+
+    for stack in &job.inputs {
+        credit_item(ctx, owner, &stack.item_id, stack.quantity)?;
+    }
+    ctx.db.craft_job().scheduled_id().delete(job_id);
+
+    spacetimedb::log::info!("[CRAFTING] {} cancelled crafting {}", owner.to_hex(), job.recipe_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn complete_craft(ctx: &ReducerContext, job: CraftJob) -> Result<(), String> {
+    // Only the scheduler may finish a craft, otherwise clients could skip the crafting time
+    if ctx.sender != ctx.identity() {
+        return Err("complete_craft is invoked by the scheduler only".to_string());
+    }
+
+    // Outputs come from the recipe; if it was removed meanwhile, the inputs are returned instead
+    let outputs = match ctx.db.recipe().recipe_id().find(&job.recipe_id) {
+        Some(recipe) => recipe.outputs,
+        None => job.inputs.clone(),
+    };
+    for stack in &outputs {
+        credit_or_drop(ctx, job.owner, stack);
+    }
+
+    spacetimedb::log::info!("[CRAFTING] {} finished crafting {}", job.owner.to_hex(), job.recipe_id);
+    Ok(())
+}
+
+// --- Survival ---
+
+#[spacetimedb::reducer]
+pub fn consume_item(ctx: &ReducerContext, slot: u32) -> Result<(), String> {
+    let identity = ctx.sender;
+    let mut player = ctx.db.player().identity().find(identity)
+        .ok_or_else(|| format!("Player with identity {} not found", identity.to_hex()))?;
+    let item = find_slot(ctx, identity, slot).ok_or_else(|| format!("Inventory slot {} is empty", slot))?;
+    let effect = item_effect(&item.item_id).ok_or_else(|| format!("{} cannot be consumed", item.item_id))?;
+
+    take_from_slot(ctx, identity, slot, 1)?;
+    player.survival.restore(&effect);
+    player.health = (player.health + effect.health).min(player.max_health);
+
+    spacetimedb::log::info!("[SURVIVAL] Player {} consumed {}", player.username, item.item_id);
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
+
+// --- Inventory ---
+
+// Inventory settings of owner, created with defaults on first use
+fn ensure_inventory(ctx: &ReducerContext, owner: Identity) -> Inventory {
+    ctx.db.inventory().owner().find(owner).unwrap_or_else(|| {
+        ctx.db.inventory().insert(Inventory {
+            owner,
+            capacity: DEFAULT_INVENTORY_CAPACITY,
+            slot_count: DEFAULT_INVENTORY_SLOTS,
+        })
+    })
+}
+
+fn find_slot(ctx: &ReducerContext, owner: Identity, slot: u32) -> Option<InventoryItem> {
+    ctx.db.inventory_item().owner().filter(owner).find(|item| item.slot == slot)
+}
+
+// Adds quantity of an item to owner's inventory, stacking with an existing slot of the same item
+fn credit_item(ctx: &ReducerContext, owner: Identity, item_id: &str, quantity: u32) -> Result<(), String> {
+    if quantity == 0 {
+        return Err("Quantity must be positive".to_string());
+    }
+    let inventory = ensure_inventory(ctx, owner);
+    let items: Vec<InventoryItem> = ctx.db.inventory_item().owner().filter(owner).collect();
+
+    let held: u32 = items.iter().map(|item| item.quantity).sum();
+    if held.saturating_add(quantity) > inventory.capacity {
+        return Err(format!(
+            "Inventory full: holding {}, cannot add {} {} (capacity {})",
+            held,
+            quantity,
+            item_id,
+            inventory.capacity
+        ));
+    }
+
+    if let Some(mut item) = items.iter().find(|item| item.item_id == item_id).cloned() {
+        item.quantity += quantity;
+        ctx.db.inventory_item().entry_id().update(item);
+        return Ok(());
+    }
+
+    let used: BTreeSet<u32> = items.iter().map(|item| item.slot).collect();
+    let slot = (0..inventory.slot_count)
+        .find(|slot| !used.contains(slot))
+        .ok_or_else(|| format!("No free inventory slot for {}", item_id))?;
+    ctx.db.inventory_item().insert(InventoryItem {
+        entry_id: 0,
+        owner,
+        item_id: item_id.to_string(),
+        quantity,
+        slot,
+    });
+    Ok(())
+}
+
+// Credits a stack to owner, or drops it at the owner's position if the inventory is full
+fn credit_or_drop(ctx: &ReducerContext, owner: Identity, stack: &ItemStack) {
+    if credit_item(ctx, owner, &stack.item_id, stack.quantity).is_ok() {
+        return;
+    }
+
+    let (planet_id, position) = match ctx.db.player().identity().find(owner) {
+        Some(player) => (player.planet_id, player.position),
+        None => match ctx.db.logged_out_player().identity().find(owner) {
+            Some(player) => (player.planet_id, player.position),
+            None => (None, Vector3 { x: 0.0, y: 0.0, z: 0.0 }),
+        },
+    };
+    ctx.db.dropped_item().insert(DroppedItem {
+        drop_id: 0,
+        planet_id,
+        position,
+        item_id: stack.item_id.clone(),
+        quantity: stack.quantity,
+        dropped_by: owner,
+        dropped_at: ctx.timestamp,
+    });
+}
+
+// Removes every (item, quantity) in cost from owner's inventory, or nothing if any is short
+fn consume_items(ctx: &ReducerContext, owner: Identity, cost: &[(&str, u32)]) -> Result<(), String> {
+    let items: Vec<InventoryItem> = ctx.db.inventory_item().owner().filter(owner).collect();
+    for &(item_id, quantity) in cost {
+        let held: u32 = items.iter().filter(|item| item.item_id == item_id).map(|item| item.quantity).sum();
+        if held < quantity {
+            return Err(format!("Need {} {}, have {}", quantity, item_id, held));
+        }
+    }
+
+    for &(item_id, quantity) in cost {
+        let mut remaining = quantity;
+        for item in items.iter().filter(|item| item.item_id == item_id) {
+            if remaining == 0 {
+                break;
+            }
+            let taken = remaining.min(item.quantity);
+            take_from_slot(ctx, owner, item.slot, taken)?;
+            remaining -= taken;
+        }
+    }
+    Ok(())
+}
+
+// Removes quantity from owner's stack in slot and returns the removed part
+fn take_from_slot(ctx: &ReducerContext, owner: Identity, slot: u32, quantity: u32) -> Result<InventoryItem, String> {
+    let mut item = find_slot(ctx, owner, slot).ok_or_else(|| format!("Inventory slot {} is empty", slot))?;
+    if quantity == 0 || quantity > item.quantity {
+        return Err(format!("Cannot take {} from slot {} holding {} {}", quantity, slot, item.quantity, item.item_id));
+    }
+
+    let mut taken = item.clone();
+    taken.quantity = quantity;
+    if quantity == item.quantity {
+        ctx.db.inventory_item().entry_id().delete(item.entry_id);
+    } else {
+        item.quantity -= quantity;
+        ctx.db.inventory_item().entry_id().update(item);
+    }
+    Ok(taken)
+}
+
+fn validate_slot(inventory: &Inventory, slot: u32) -> Result<(), String> {
+    if slot >= inventory.slot_count {
+        return Err(format!("Inventory slot {} out of range (0..{})", slot, inventory.slot_count));
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn add_inventory_item(ctx: &ReducerContext, owner: Identity, item_id: String, quantity: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    if item_id.is_empty() {
+        return Err("Item id must not be empty".to_string());
+    }
+    credit_item(ctx, owner, &item_id, quantity)?;
+    spacetimedb::log::info!("[INVENTORY] Granted {} x{} to {}", item_id, quantity, owner.to_hex());
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_inventory_item(ctx: &ReducerContext, slot: u32, quantity: u32) -> Result<(), String> {
+    let removed = take_from_slot(ctx, ctx.sender, slot, quantity)?;
+    spacetimedb::log::info!(
+        "[INVENTORY] {} removed {} x{} from slot {}",
+        ctx.sender.to_hex(),
+        removed.item_id,
+        removed.quantity,
+        slot
+    );
+    Ok(())
+}
+
+// Moves a stack to another slot: into an empty slot, merging with the same item, or swapping
+#[spacetimedb::reducer]
+pub fn move_inventory_item(ctx: &ReducerContext, from_slot: u32, to_slot: u32) -> Result<(), String> {
+    let owner = ctx.sender;
+    let inventory = ensure_inventory(ctx, owner);
+    validate_slot(&inventory, to_slot)?;
+    if from_slot == to_slot {
+        return Ok(());
+    }
+
+    let mut source = find_slot(ctx, owner, from_slot).ok_or_else(|| format!("Inventory slot {} is empty", from_slot))?;
+    match find_slot(ctx, owner, to_slot) {
+        Some(mut target) if target.item_id == source.item_id => {
+            target.quantity += source.quantity;
+            ctx.db.inventory_item().entry_id().update(target);
+            ctx.db.inventory_item().entry_id().delete(source.entry_id);
+        }
+        Some(mut target) => {
+            target.slot = from_slot;
+            ctx.db.inventory_item().entry_id().update(target);
+            source.slot = to_slot;
+            ctx.db.inventory_item().entry_id().update(source);
+        }
+        None => {
+            source.slot = to_slot;
+            ctx.db.inventory_item().entry_id().update(source);
+        }
+    }
+    Ok(())
+}
+
+// Moves part of a stack into an empty slot
+#[spacetimedb::reducer]
+pub fn split_inventory_item(ctx: &ReducerContext, slot: u32, quantity: u32, to_slot: u32) -> Result<(), String> {
+    let owner = ctx.sender;
+    let inventory = ensure_inventory(ctx, owner);
+    validate_slot(&inventory, to_slot)?;
+    if find_slot(ctx, owner, to_slot).is_some() {
+        return Err(format!("Inventory slot {} is not empty", to_slot));
+    }
+
+    let source = find_slot(ctx, owner, slot).ok_or_else(|| format!("Inventory slot {} is empty", slot))?;
+    if quantity >= source.quantity {
+        return Err(format!("Cannot split {} off a stack of {}", quantity, source.quantity));
+    }
+
+    let mut split = take_from_slot(ctx, owner, slot, quantity)?;
+    split.entry_id = 0;
+    split.slot = to_slot;
+    ctx.db.inventory_item().insert(split);
+    Ok(())
+}
+
+// Drops part of a stack into the world at the player's position
+#[spacetimedb::reducer]
+pub fn drop_inventory_item(ctx: &ReducerContext, slot: u32, quantity: u32) -> Result<(), String> {
+    let owner = ctx.sender;
+    let player = ctx.db.player().identity().find(owner)
+        .ok_or_else(|| format!("Player with identity {} not found", owner.to_hex()))?;
+
+    let dropped = take_from_slot(ctx, owner, slot, quantity)?;
+    ctx.db.dropped_item().insert(DroppedItem {
+        drop_id: 0,
+        planet_id: player.planet_id,
+        position: player.position.clone(),
+        item_id: dropped.item_id.clone(),
+        quantity: dropped.quantity,
+        dropped_by: owner,
+        dropped_at: ctx.timestamp,
+    });
+
+    spacetimedb::log::info!(
+        "[INVENTORY] {} dropped {} x{} at ({:.1}, {:.1}, {:.1})",
+        player.username,
+        dropped.item_id,
+        dropped.quantity,
+        player.position.x,
+        player.position.y,
+        player.position.z
+    );
+    Ok(())
+}
+
+// --- Player Management Reducers ---
+
+#[spacetimedb::reducer]
+pub fn register_player(ctx: &ReducerContext, username: String) -> Result<(), String> {
+    let identity = ctx.sender;
+    spacetimedb::log::info!("[REGISTER] Registering player: {} with identity: {}", username, identity.to_hex());
+    
+    // Check if player already exists
+    if ctx.db.player().identity().find(identity).is_some() {
+        return Err(format!("Player with identity {} already registered", identity.to_hex()));
+    }
+    
+    // Check if username is already taken, including by players who are logged out
+    if ctx.db.player().username().find(&username).is_some()
+        || ctx.db.logged_out_player().username().find(&username).is_some()
+    {
+        return Err(format!("Username '{}' is already taken", username));
+    }
+    
+    // Create new player
+    let new_player = PlayerData {
+        identity,
+        username: username.clone(),
+        planet_id: None,
+        chunk_id: 0,
+        view_radius: DEFAULT_VIEW_RADIUS,
+        position: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+        rotation: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
         health: 100,
         max_health: 100,
         mana: 100,
         max_mana: 100,
+        survival: SurvivalStats::full(),
+        vertical_velocity: 0.0,
+        grounded: false,
         is_moving: false,
         is_running: false,
         last_input_seq: 0,
@@ -332,7 +2275,8 @@ pub fn register_player(ctx: &ReducerContext, username: String) -> Result<(), Str
         last_update: ctx.timestamp,
     };
     
-    ctx.db.player().insert(new_player)?;
+    ctx.db.player().insert(new_player);
+    ensure_inventory(ctx, identity);
     spacetimedb::log::info!("[REGISTER] Player {} registered successfully", username);
     
     Ok(())
@@ -347,7 +2291,8 @@ pub fn update_player_input(
     
     // Find the player
     let mut player = ctx.db.player()
-        .find(|p| p.identity == identity)
+        .identity()
+        .find(identity)
         .ok_or_else(|| format!("Player with identity {} not found", identity.to_hex()))?;
     
     // Check sequence number to prevent old updates
@@ -355,38 +2300,98 @@ pub fn update_player_input(
         return Ok(()); // Ignore old or duplicate updates
     }
     
-    // Calculate delta time (assuming 50ms tick rate)
-    let delta_time = 0.05; // 50ms in seconds
-    
-    // Store old position and rotation for change detection
-    let old_position = player.position.clone();
+    // Looking around is immediate; movement is simulated by game_tick from the stored input
     let old_rotation = player.rotation.clone();
-    
-    // Update player position and rotation based on input
-    let (new_position, new_rotation) = update_player_position(
-        &player.position,
-        &player.rotation,
-        &input,
-        delta_time,
-    );
-    
-    // Update player state
-    player.position = new_position;
-    player.rotation = new_rotation;
+    player.rotation = update_player_rotation(&player.rotation, &input);
     player.input = input.clone();
     player.last_input_seq = input.sequence;
-    player.last_update = ctx.timestamp;
     
     // Determine movement state
     player.is_moving = input.w || input.s || input.a || input.d;
     player.is_running = player.is_moving && input.shift;
     
+    // Log significant changes
+    if is_significant_rotation(&old_rotation, &player.rotation, 0.05) {
+        spacetimedb::log::debug!(
+            "[UPDATE] Player {} turned to ({:.2}, {:.2})",
+            player.username,
+            player.rotation.x,
+            player.rotation.y
+        );
+    }
+    
     // Update the player in the database
-    ctx.db.player().update(&player);
+    ctx.db.player().identity().update(player);
+    
+    Ok(())
+}
+
+// Moves a player by their stored input over delta_time seconds
+fn simulate_movement(ctx: &ReducerContext, player: &mut PlayerData, planet: Option<&Planet>, delta_time: f32) {
+    // Store old position for change detection
+    let old_position = player.position.clone();
+    
+    // Players on a planet fall with its gravity and collide with its terrain
+    let gravity = planet.map_or(0.0, |planet| planet.gravity);
+    let mode = planet.map_or(MovementMode::Flat, |planet| planet.movement_mode);
+    let mut terrain = planet.map(|planet| TerrainSampler::new(ctx, planet));
+    let mut terrain_error = None;
+    let mut density = |position: &Vector3| {
+        let Some(terrain) = terrain.as_mut() else {
+            return f32::MAX;
+        };
+        terrain.density(position).unwrap_or_else(|e| {
+            terrain_error.get_or_insert(e);
+            f32::MAX
+        })
+    };
+    
+    let movement = update_player_position(
+        &MovementState {
+            position: player.position.clone(),
+            rotation: player.rotation.clone(),
+            vertical_velocity: player.vertical_velocity,
+            grounded: player.grounded,
+        },
+        &player.input,
+        gravity,
+        mode,
+        delta_time,
+        &mut density,
+    );
+    
+    // Terrain that cannot be read is not treated as air; the player waits where they are
+    if let Some(e) = terrain_error {
+        spacetimedb::log::warn!(
+            "[PHYSICS] Cannot read terrain around player {}: {}; keeping them at ({:.2}, {:.2}, {:.2})",
+            player.username,
+            e,
+            player.position.x,
+            player.position.y,
+            player.position.z
+        );
+        player.vertical_velocity = 0.0;
+        return;
+    }
+    
+    // Update player state
+    player.position = movement.position;
+    player.rotation = movement.rotation;
+    player.vertical_velocity = movement.vertical_velocity;
+    player.grounded = movement.grounded;
+    
+    // Players who leave the planet bounds are respawned rather than clamped
+    match planet {
+        Some(planet) if !is_in_planet_bounds(planet, &player.position) => respawn_player(ctx, player, planet),
+        None if !validate_position(&player.position, &PlanetBounds::Unbounded) => {
+            player.position = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
+            player.vertical_velocity = 0.0;
+        }
+        _ => {}
+    }
     
     // Log significant changes
-    if is_significant_movement(&old_position, &player.position, 0.1) ||
-       is_significant_rotation(&old_rotation, &player.rotation, 0.05) {
+    if is_significant_movement(&old_position, &player.position, 0.1) {
         spacetimedb::log::debug!(
             "[UPDATE] Player {} moved to ({:.2}, {:.2}, {:.2})",
             player.username,
@@ -395,14 +2400,195 @@ pub fn update_player_input(
             player.position.z
         );
     }
+}
+
+// --- Chunk Interest ---
+
+// Chunk containing the player, or 0 while the player is not on a planet
+fn current_chunk_id(player: &PlayerData) -> u64 {
+    player.planet_id
+        .and_then(|planet_id| world_to_chunk(planet_id, &player.position).chunk_id().ok())
+        .unwrap_or(0)
+}
+
+// Brings a player's chunk_interest rows in line with their position and view radius,
+// touching only the rows that change so subscribers receive minimal updates
+fn refresh_chunk_interest(ctx: &ReducerContext, player: &mut PlayerData) {
+    player.chunk_id = current_chunk_id(player);
+
+    let wanted: BTreeMap<u64, ChunkCoord> = match player.planet_id {
+        Some(planet_id) if player.chunk_id != 0 => {
+            let center = world_to_chunk(planet_id, &player.position);
+            chunks_in_view(&center, player.view_radius as i32)
+                .into_iter()
+                .filter_map(|coord| coord.chunk_id().ok().map(|chunk_id| (chunk_id, coord)))
+                .collect()
+        }
+        _ => BTreeMap::new(),
+    };
+
+    let current: Vec<ChunkInterest> = ctx.db.chunk_interest().identity().filter(player.identity).collect();
+    let mut kept = BTreeSet::new();
+    for interest in current {
+        if wanted.contains_key(&interest.chunk_id) {
+            kept.insert(interest.chunk_id);
+        } else {
+            ctx.db.chunk_interest().interest_id().delete(interest.interest_id);
+        }
+    }
+
+    for (chunk_id, coord) in wanted {
+        if kept.contains(&chunk_id) {
+            continue;
+        }
+        ctx.db.chunk_interest().insert(ChunkInterest {
+            interest_id: 0,
+            identity: player.identity,
+            chunk_id,
+            planet_id: coord.planet_id,
+            chunk_x: coord.x,
+            chunk_y: coord.y,
+            chunk_z: coord.z,
+        });
+    }
+}
+
+fn clear_chunk_interest(ctx: &ReducerContext, identity: Identity) {
+    let interest_ids: Vec<u64> = ctx.db.chunk_interest()
+        .identity()
+        .filter(identity)
+        .map(|interest| interest.interest_id)
+        .collect();
+    for interest_id in interest_ids {
+        ctx.db.chunk_interest().interest_id().delete(interest_id);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn enter_planet(ctx: &ReducerContext, planet_id: u32) -> Result<(), String> {
+    let identity = ctx.sender;
+    let planet = find_planet(ctx, planet_id)?;
+    let mut player = ctx.db.player()
+        .identity()
+        .find(identity)
+        .ok_or_else(|| format!("Player with identity {} not found", identity.to_hex()))?;
+    
+    player.planet_id = Some(planet_id);
+    if !is_in_planet_bounds(&planet, &player.position) {
+        respawn_player(ctx, &mut player, &planet);
+    }
+    refresh_chunk_interest(ctx, &mut player);
+    
+    spacetimedb::log::info!("[INTEREST] Player {} entered planet {}", player.username, planet.name);
+    ctx.db.player().identity().update(player);
+    
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_view_radius(ctx: &ReducerContext, view_radius: u32) -> Result<(), String> {
+    let identity = ctx.sender;
+    
+    if view_radius > MAX_VIEW_RADIUS {
+        return Err(format!("View radius {} exceeds maximum of {}", view_radius, MAX_VIEW_RADIUS));
+    }
     
+    let mut player = ctx.db.player()
+        .identity()
+        .find(identity)
+        .ok_or_else(|| format!("Player with identity {} not found", identity.to_hex()))?;
+    
+    player.view_radius = view_radius;
+    refresh_chunk_interest(ctx, &mut player);
+    ctx.db.player().identity().update(player);
+    
+    Ok(())
+}
+
+// --- World Clock ---
+
+// Clock of a planet, started at noon on first use
+fn ensure_world_clock(ctx: &ReducerContext, planet_id: u32) -> WorldClock {
+    ctx.db.world_clock().planet_id().find(planet_id).unwrap_or_else(|| {
+        ctx.db.world_clock().insert(WorldClock {
+            planet_id,
+            time_of_day: DEFAULT_TIME_OF_DAY,
+            day_count: 0,
+            time_scale: 1.0,
+            paused: false,
+            updated_at: ctx.timestamp,
+        })
+    })
+}
+
+// Re-anchors a clock at the current time before it changes; paused clocks only move their timestamp
+fn advance_world_clock(ctx: &ReducerContext, mut clock: WorldClock) -> WorldClock {
+    if !clock.paused {
+        let elapsed = ctx.timestamp.duration_since(clock.updated_at).unwrap_or_default();
+        (clock.time_of_day, clock.day_count) =
+            advance_clock(clock.time_of_day, clock.day_count, elapsed.as_secs_f64(), clock.time_scale);
+    }
+    clock.updated_at = ctx.timestamp;
+    clock
+}
+
+#[spacetimedb::reducer]
+pub fn set_world_time(ctx: &ReducerContext, planet_id: u32, time_of_day: f32, day_count: Option<u32>) -> Result<(), String> {
+    require_admin(ctx)?;
+    find_planet(ctx, planet_id)?;
+    if !(0.0..HOURS_PER_DAY).contains(&time_of_day) {
+        return Err(format!("Time of day {} must be in [0, {})", time_of_day, HOURS_PER_DAY));
+    }
+
+    let mut clock = ensure_world_clock(ctx, planet_id);
+    clock.time_of_day = time_of_day;
+    clock.day_count = day_count.unwrap_or(clock.day_count);
+    clock.updated_at = ctx.timestamp;
+    ctx.db.world_clock().planet_id().update(clock);
+
+    spacetimedb::log::info!("[CLOCK] Planet {} time set to {:.2}h", planet_id, time_of_day);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_time_scale(ctx: &ReducerContext, planet_id: u32, time_scale: f32) -> Result<(), String> {
+    require_admin(ctx)?;
+    find_planet(ctx, planet_id)?;
+    if !(time_scale > 0.0 && time_scale <= MAX_TIME_SCALE) {
+        return Err(format!("Time scale {} must be in (0, {}]", time_scale, MAX_TIME_SCALE));
+    }
+
+    // Settle the time passed at the old scale first
+    let mut clock = advance_world_clock(ctx, ensure_world_clock(ctx, planet_id));
+    clock.time_scale = time_scale;
+    ctx.db.world_clock().planet_id().update(clock);
+
+    spacetimedb::log::info!("[CLOCK] Planet {} time scale set to {}", planet_id, time_scale);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_world_clock_paused(ctx: &ReducerContext, planet_id: u32, paused: bool) -> Result<(), String> {
+    require_admin(ctx)?;
+    find_planet(ctx, planet_id)?;
+
+    let mut clock = advance_world_clock(ctx, ensure_world_clock(ctx, planet_id));
+    clock.paused = paused;
+    ctx.db.world_clock().planet_id().update(clock);
+
+    spacetimedb::log::info!("[CLOCK] Planet {} clock {}", planet_id, if paused { "paused" } else { "resumed" });
     Ok(())
 }
 
 // --- Game Loop ---
 
 #[spacetimedb::reducer]
-pub fn game_tick(ctx: &ReducerContext) -> Result<(), String> {
+pub fn game_tick(ctx: &ReducerContext, _arg: GameTickSchedule) -> Result<(), String> {
+    // Only the scheduler runs the game loop, otherwise clients could add ticks of regeneration
+    if ctx.sender != ctx.identity() {
+        return Err("game_tick is invoked by the scheduler only".to_string());
+    }
+    
     let player_count = ctx.db.player().count();
     
     if player_count > 0 {
@@ -412,20 +2598,64 @@ pub fn game_tick(ctx: &ReducerContext) -> Result<(), String> {
         // - Health/mana regeneration
         // - Environmental effects
         // - AI updates
-        // - Physics simulation
         // - Cleanup tasks
         
-        // Example: Health regeneration
-        for mut player in ctx.db.player().iter() {
-            if player.health < player.max_health {
-                player.health = (player.health + 1).min(player.max_health);
-                ctx.db.player().update(&player);
+        let planets: BTreeMap<u32, Planet> = ctx.db.planet().iter().map(|planet| (planet.planet_id, planet)).collect();
+        let now_micros = ctx.timestamp.to_micros_since_unix_epoch();
+        
+        // Per-player upkeep: movement, survival stats, regeneration and chunk interest
+        let players: Vec<PlayerData> = ctx.db.player().iter().collect();
+        for mut player in players {
+            // Simulate the real time since the player was last simulated, so neither client
+            // send rate nor a late or extra tick changes how fast players move
+            let elapsed = ctx.timestamp.duration_since(player.last_update).unwrap_or_default();
+            let delta_time = elapsed.as_secs_f32().min(MAX_TICK_DELTA_SECS);
+            
+            // Depletion damage is dealt once per whole second crossed since then
+            let new_second = now_micros / 1_000_000 != player.last_update.to_micros_since_unix_epoch() / 1_000_000;
+            player.last_update = ctx.timestamp;
+            
+            // Players out of stamina cannot sprint
+            if player.survival.stamina <= 0.0 {
+                player.input.shift = false;
+                player.is_running = false;
             }
             
-            if player.mana < player.max_mana {
+            let planet = player.planet_id.and_then(|planet_id| planets.get(&planet_id));
+            simulate_movement(ctx, &mut player, planet, delta_time);
+            
+            player.survival.decay(player.is_running, delta_time);
+            let depleted = player.survival.depleted_count();
+            if depleted > 0 {
+                if new_second && player.health > 0 {
+                    player.health = (player.health - depleted * DEPLETION_DAMAGE_PER_SEC).max(0);
+                    if player.health == 0 {
+                        spacetimedb::log::info!("[SURVIVAL] Player {} died of hunger or thirst", player.username);
+                    }
+                }
+            } else if player.health < player.max_health || player.mana < player.max_mana {
+                player.health = (player.health + 1).min(player.max_health);
                 player.mana = (player.mana + 2).min(player.max_mana);
-                ctx.db.player().update(&player);
             }
+            
+            // Stream chunks around the player whenever they cross a chunk border
+            if current_chunk_id(&player) != player.chunk_id {
+                refresh_chunk_interest(ctx, &mut player);
+            }
+            
+            // Survival stats and last_update change every tick, so every player row is written
+            ctx.db.player().identity().update(player);
+        }
+    }
+    
+    // Clocks are re-anchored once per in-game minute rather than every tick, so their rows
+    // change at a rate independent of the tick rate
+    let clocks: Vec<WorldClock> = ctx.db.world_clock().iter().filter(|clock| !clock.paused).collect();
+    for clock in clocks {
+        let minute = clock_minute(clock.time_of_day, clock.day_count);
+        let clock = advance_world_clock(ctx, clock);
+        if clock_minute(clock.time_of_day, clock.day_count) != minute {
+            ctx.db.world_clock().planet_id().update(clock);
         }
     }
     
@@ -0,0 +1,130 @@
+/*
+ * Infinia Multiplayer - mining.rs
+ *
+ * This file contains the mining rules used by the mining reducers. It mirrors
+ * debug/modules/mining/miningSystem.ts so that server yields match what the
+ * client's mining UI predicts.
+ *
+ * Key functions:
+ * - tool_stats: Power, speed, efficiency and durability of each tool type
+ * - repair_cost / upgrade_path: Inventory resources consumed to maintain tools
+ * - material_hardness / material_value: Per-element mining tables
+ * - mining_yield: Resources extracted from one mined sample
+ * - tool_damage: Durability lost when mining a material
+ *
+ * Mining rules:
+ * - A tool can only mine materials whose hardness does not exceed its power
+ * - Yield is floor(value * efficiency), at least 1, per generated sample carved to air
+ * - Tool damage is floor(hardness / 2), at least 1; tools break at 0 durability
+ * - Upgrades follow Hand -> Pick -> Drill -> Laser
+ *
+ * When modifying:
+ * - Keep the tables in sync with MATERIAL_HARDNESS / MATERIAL_VALUE in miningSystem.ts
+ * - Unknown element symbols use the 'default' entries of those tables
+ */
+
+use crate::common::ToolType;
+
+// --- Tools ---
+
+pub struct ToolStats {
+    pub power: u32,                  // Highest material hardness the tool can mine (1-10)
+    pub speed: u32,                  // Mining speed (1-10); faster tools remove more density
+    pub efficiency: f32,             // Resource extraction efficiency (0.1-1.0)
+    pub max_durability: Option<u32>, // None for tools that never wear out
+}
+
+pub fn tool_stats(tool: ToolType) -> ToolStats {
+    match tool {
+        ToolType::Hand => ToolStats { power: 1, speed: 1, efficiency: 0.5, max_durability: None },
+        ToolType::Pick => ToolStats { power: 3, speed: 2, efficiency: 0.7, max_durability: Some(100) },
+        ToolType::Drill => ToolStats { power: 6, speed: 5, efficiency: 0.8, max_durability: Some(200) },
+        ToolType::Laser => ToolStats { power: 10, speed: 8, efficiency: 0.9, max_durability: Some(500) },
+    }
+}
+
+// Resources (element symbol, quantity) consumed to restore a tool to full durability
+pub fn repair_cost(tool: ToolType) -> &'static [(&'static str, u32)] {
+    match tool {
+        ToolType::Hand => &[],
+        ToolType::Pick => &[("Fe", 2)],
+        ToolType::Drill => &[("Fe", 3), ("Cu", 2)],
+        ToolType::Laser => &[("Ti", 2), ("Si", 3)],
+    }
+}
+
+// Next tool tier and the resources consumed to reach it; None at the top tier
+pub fn upgrade_path(tool: ToolType) -> Option<(ToolType, &'static [(&'static str, u32)])> {
+    match tool {
+        ToolType::Hand => Some((ToolType::Pick, &[("Fe", 5), ("C", 2)])),
+        ToolType::Pick => Some((ToolType::Drill, &[("Fe", 10), ("Cu", 5)])),
+        ToolType::Drill => Some((ToolType::Laser, &[("Ti", 8), ("Si", 10), ("Au", 2)])),
+        ToolType::Laser => None,
+    }
+}
+
+// --- Materials ---
+
+// Hardness levels (1-10)
+pub fn material_hardness(symbol: &str) -> u32 {
+    match symbol {
+        // Common materials (easy to mine)
+        "H" | "C" | "N" | "O" | "Na" | "S" | "Cl" | "K" => 1,
+        "Mg" | "Al" | "P" | "Ca" => 2,
+        "Si" => 3,
+
+        // Metals (medium hardness)
+        "Ti" | "V" | "Cr" | "Fe" | "Co" | "Ni" => 4,
+        "Mn" | "Cu" => 3,
+        "Zn" => 2,
+
+        // Precious metals (harder)
+        "Ag" => 5,
+        "Au" | "Pt" => 6,
+
+        // Rare and exotic (hardest)
+        "U" | "Pu" | "Th" | "W" => 7,
+        "Xe" | "Rn" | "Os" | "Ir" => 8,
+        "Hg" => 5,
+        "Pd" | "Rh" | "Ru" => 6,
+
+        _ => 4,
+    }
+}
+
+// Value (1-10, determines yield amount)
+pub fn material_value(symbol: &str) -> u32 {
+    match symbol {
+        // Common materials (low value)
+        "H" | "C" | "N" | "O" | "Na" | "Cl" | "K" | "Ca" => 1,
+        "Mg" | "Al" | "Si" | "P" | "S" => 2,
+
+        // Metals (medium value)
+        "Ti" | "V" | "Co" | "Ni" => 4,
+        "Cr" | "Mn" | "Fe" | "Cu" => 3,
+        "Zn" => 2,
+
+        // Precious metals (high value)
+        "Ag" => 6,
+        "Au" => 8,
+        "Pt" => 9,
+
+        // Rare and exotic (highest value)
+        "U" | "Th" | "Os" | "Pd" | "Rh" => 7,
+        "Pu" | "Ir" => 8,
+        "Xe" | "Rn" | "Ru" => 6,
+        "Hg" | "W" => 5,
+
+        _ => 3,
+    }
+}
+
+// --- Mining Results ---
+
+pub fn mining_yield(symbol: &str, tool: &ToolStats) -> u32 {
+    ((material_value(symbol) as f32 * tool.efficiency).floor() as u32).max(1)
+}
+
+pub fn tool_damage(symbol: &str) -> u32 {
+    (material_hardness(symbol) / 2).max(1)
+}
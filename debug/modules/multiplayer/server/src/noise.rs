@@ -0,0 +1,405 @@
+/*
+ * Infinia Multiplayer - noise.rs
+ *
+ * This file contains the deterministic terrain generator used as the procedural
+ * base for every terrain chunk. On flat planets it mirrors the client's
+ * generateNoiseMap so that the server and clients agree on unmodified terrain.
+ *
+ * Key components:
+ * - SimplexNoise: Port of simplex-noise v4 createNoise3D(() => seed)
+ * - TerrainGenerator: Three-layer FBM plus an altitude offset, and the base
+ *   material of each sample chosen from the planet's composition
+ * - TerrainShape: Flat planets use the client's world-Y offset; spherical planets
+ *   measure altitude from the planet surface at their radius around the origin
+ * - default_noise_params: Per-planet-type layer scales (terrainConfigurator.ts)
+ * - planet_seed: Stable default seed in [0, 1) derived from a planet name
+ *
+ * Determinism:
+ * - All noise math is done in f64, like the JavaScript implementation
+ * - Results are narrowed to f32 exactly where the client writes Float32Arrays
+ * - Never use non-deterministic sources (time, rng) in this module
+ *
+ * When modifying:
+ * - Keep the layer table in sync with debug/terrainGenerationUtils/terrainConfigurator.ts
+ * - The table only provides defaults; each planet row stores its own seed and NoiseParams
+ * - Changing generation changes the base every stored chunk delta is relative to
+ */
+
+use crate::common::{Vector3, NoiseParams, PlanetMaterial};
+
+// --- Simplex Noise ---
+
+const F3: f64 = 1.0 / 3.0;
+const G3: f64 = 1.0 / 6.0;
+
+const GRAD3: [[f64; 3]; 12] = [
+    [1.0, 1.0, 0.0], [-1.0, 1.0, 0.0], [1.0, -1.0, 0.0], [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0], [-1.0, 0.0, 1.0], [1.0, 0.0, -1.0], [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0], [0.0, -1.0, 1.0], [0.0, 1.0, -1.0], [0.0, -1.0, -1.0],
+];
+
+pub struct SimplexNoise {
+    perm: [u8; 512],
+    perm_grad: [[f64; 3]; 512],
+}
+
+impl SimplexNoise {
+    // Equivalent to createNoise3D(() => seed): the "random" source always returns seed
+    pub fn new(seed: f64) -> Self {
+        let mut perm = [0u8; 512];
+        for (i, value) in perm.iter_mut().enumerate().take(256) {
+            *value = i as u8;
+        }
+        for i in 0..255 {
+            // ~~(random() * (256 - i)) truncates toward zero
+            let r = (i + (seed * (256 - i) as f64) as usize).min(255);
+            perm.swap(i, r);
+        }
+        for i in 256..512 {
+            perm[i] = perm[i - 256];
+        }
+
+        let mut perm_grad = [[0.0; 3]; 512];
+        for (grad, &p) in perm_grad.iter_mut().zip(perm.iter()) {
+            *grad = GRAD3[p as usize % 12];
+        }
+
+        SimplexNoise { perm, perm_grad }
+    }
+
+    fn corner(&self, gi: usize, x: f64, y: f64, z: f64) -> f64 {
+        let t = 0.6 - x * x - y * y - z * z;
+        if t < 0.0 {
+            return 0.0;
+        }
+        let t2 = t * t;
+        let grad = &self.perm_grad[gi];
+        t2 * t2 * (grad[0] * x + grad[1] * y + grad[2] * z)
+    }
+
+    pub fn noise3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        // Skew the input space to find the containing simplex cell
+        let s = (x + y + z) * F3;
+        let i = (x + s).floor() as i32;
+        let j = (y + s).floor() as i32;
+        let k = (z + s).floor() as i32;
+        let t = (i + j + k) as f64 * G3;
+        let x0 = x - (i as f64 - t);
+        let y0 = y - (j as f64 - t);
+        let z0 = z - (k as f64 - t);
+
+        // Determine which simplex we are in
+        let (i1, j1, k1, i2, j2, k2) = if x0 >= y0 {
+            if y0 >= z0 {
+                (1, 0, 0, 1, 1, 0)
+            } else if x0 >= z0 {
+                (1, 0, 0, 1, 0, 1)
+            } else {
+                (0, 0, 1, 1, 0, 1)
+            }
+        } else if y0 < z0 {
+            (0, 0, 1, 0, 1, 1)
+        } else if x0 < z0 {
+            (0, 1, 0, 0, 1, 1)
+        } else {
+            (0, 1, 0, 1, 1, 0)
+        };
+
+        let x1 = x0 - i1 as f64 + G3;
+        let y1 = y0 - j1 as f64 + G3;
+        let z1 = z0 - k1 as f64 + G3;
+        let x2 = x0 - i2 as f64 + 2.0 * G3;
+        let y2 = y0 - j2 as f64 + 2.0 * G3;
+        let z2 = z0 - k2 as f64 + 2.0 * G3;
+        let x3 = x0 - 1.0 + 3.0 * G3;
+        let y3 = y0 - 1.0 + 3.0 * G3;
+        let z3 = z0 - 1.0 + 3.0 * G3;
+
+        let ii = (i & 255) as usize;
+        let jj = (j & 255) as usize;
+        let kk = (k & 255) as usize;
+        let perm = |index: usize| self.perm[index] as usize;
+
+        let gi0 = ii + perm(jj + perm(kk));
+        let gi1 = ii + i1 + perm(jj + j1 + perm(kk + k1));
+        let gi2 = ii + i2 + perm(jj + j2 + perm(kk + k2));
+        let gi3 = ii + 1 + perm(jj + 1 + perm(kk + 1));
+
+        32.0 * (self.corner(gi0, x0, y0, z0)
+            + self.corner(gi1, x1, y1, z1)
+            + self.corner(gi2, x2, y2, z2)
+            + self.corner(gi3, x3, y3, z3))
+    }
+}
+
+// --- Terrain Generation ---
+
+pub const DEFAULT_NOISE_LAYERS: [f32; 3] = [75.0, 25.0, 10.0];
+
+// Height of the flat surface, like the client's world-Y offset
+const FLAT_SURFACE_HEIGHT: f64 = 10.0;
+
+// Surface the altitude offset is measured from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TerrainShape {
+    Flat,        // Horizontal surface at y = FLAT_SURFACE_HEIGHT
+    Sphere(f32), // Sphere of this radius around the world origin
+}
+
+// Scale of material deposits and the offset that decorrelates them from the density noise
+const MATERIAL_SCALE: f64 = 12.0;
+const MATERIAL_OFFSET: f64 = 1000.0;
+
+pub struct TerrainGenerator {
+    noise: SimplexNoise,
+    layers: [f64; 3],
+    material_thresholds: Vec<f64>, // Cumulative normalized abundance per palette entry
+    shape: TerrainShape,
+}
+
+impl TerrainGenerator {
+    pub fn new(seed: f64, params: &NoiseParams, materials: &[PlanetMaterial], shape: TerrainShape) -> Self {
+        let total: f64 = materials.iter().map(|m| m.abundance as f64).sum();
+        let mut cumulative = 0.0;
+        let material_thresholds = materials
+            .iter()
+            .map(|material| {
+                cumulative += material.abundance as f64;
+                if total > 0.0 { cumulative / total } else { 1.0 }
+            })
+            .collect();
+
+        TerrainGenerator {
+            noise: SimplexNoise::new(seed),
+            layers: [
+                params.base_scale as f64,
+                params.medium_scale as f64,
+                params.fine_scale as f64,
+            ],
+            material_thresholds,
+            shape,
+        }
+    }
+
+    // Palette index of the planet material at a sample; 0 when the planet has no palette
+    pub fn material(&self, sample: &Vector3) -> u8 {
+        let x = sample.x as f64 / MATERIAL_SCALE + MATERIAL_OFFSET;
+        let y = sample.y as f64 / MATERIAL_SCALE;
+        let z = sample.z as f64 / MATERIAL_SCALE - MATERIAL_OFFSET;
+        let t = ((self.noise.noise3d(x, y, z) + 1.0) / 2.0).clamp(0.0, 1.0);
+
+        self.material_thresholds
+            .iter()
+            .position(|&threshold| t < threshold)
+            .unwrap_or(self.material_thresholds.len().saturating_sub(1)) as u8
+    }
+
+    // Density at a world-space sample; negative values are solid
+    pub fn density(&self, sample: &Vector3) -> f32 {
+        let x = sample.x as f64;
+        let y = sample.y as f64;
+        let z = sample.z as f64;
+
+        // Bias toward solid below the surface and toward air above it
+        let altitude = match self.shape {
+            TerrainShape::Flat => y - FLAT_SURFACE_HEIGHT,
+            TerrainShape::Sphere(radius) => (x * x + y * y + z * z).sqrt() - radius as f64,
+        };
+        let offset = if altitude < 0.0 {
+            (altitude * 0.2).max(-20.0)
+        } else {
+            altitude * 0.05
+        };
+
+        let [base, medium, fine] = self.layers;
+        let value = self.noise.noise3d(x / base, y / base, z / base)
+            + 0.5 * self.noise.noise3d(x / medium, y / medium, z / medium)
+            + 0.25 * self.noise.noise3d(x / fine, y / fine, z / fine)
+            + offset;
+
+        value as f32
+    }
+}
+
+// FNV-1a hash of the planet name, mapped to [0, 1) like Math.random()
+pub fn planet_seed(planet_type: &str) -> f64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in planet_type.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+pub fn default_noise_params(planet_type: &str) -> NoiseParams {
+    let [base_scale, medium_scale, fine_scale] = noise_layers_for_planet(planet_type);
+    NoiseParams { base_scale, medium_scale, fine_scale }
+}
+
+// [Base Layer, Medium Detail, Fine Detail] scales; larger values = larger features
+fn noise_layers_for_planet(planet_type: &str) -> [f32; 3] {
+    match planet_type {
+        "barren_planet" => [60.0, 20.0, 8.0],
+        "desert_planet" => [100.0, 40.0, 15.0],
+        "ice_planet" => [120.0, 50.0, 25.0],
+        "lava_planet" | "lava_world" | "magma_ocean_planet" | "volcanic_planet" => [50.0, 15.0, 5.0],
+        "ocean_planet" | "ocean_world" | "water_planet" => [150.0, 80.0, 40.0],
+        "terrestrial_planet" | "earth_analog_planet" | "silicate_planet" => [75.0, 25.0, 10.0],
+        "gas_giant" | "cloudless_gas_giant" | "helium_planet" | "water_clouds_gas_giant" => [200.0, 100.0, 50.0],
+        "crater_planet" => [70.0, 10.0, 5.0],
+        "alkali_metal_clouds_gas_giant" => [220.0, 110.0, 55.0],
+        "ammonia_clouds_gas_giant" => [210.0, 105.0, 50.0],
+        "ammonia_planet" => [130.0, 60.0, 30.0],
+        "brown_dwarf" | "sub_brown_dwarf" | "super_jupiter" => [240.0, 120.0, 60.0],
+        "carbon_planet" => [55.0, 18.0, 7.0],
+        "chlorine_planet" => [90.0, 35.0, 15.0],
+        "chthonian_planet" => [40.0, 10.0, 4.0],
+        "cold_eyeball_planet" => [125.0, 50.0, 20.0],
+        "coreless_planet" | "forest_planet" | "super_venus" => [80.0, 30.0, 12.0],
+        "diamond_planet" => [60.0, 20.0, 6.0],
+        "disrupted_planet" => [50.0, 18.0, 7.0],
+        "dwarf_planet" => [100.0, 40.0, 18.0],
+        "eccentric_jupiter" => [190.0, 85.0, 38.0],
+        "eyeball_planet" => [110.0, 40.0, 15.0],
+        "gas_dwarf" => [190.0, 95.0, 48.0],
+        "hot_desert_planet" => [110.0, 45.0, 18.0],
+        "hot_eyeball_planet" => [80.0, 30.0, 10.0],
+        "hot_jupiter" => [180.0, 80.0, 35.0],
+        "hot_neptune" => [170.0, 75.0, 30.0],
+        "hycean_planet" => [140.0, 70.0, 35.0],
+        "ice_giant" => [210.0, 100.0, 60.0],
+        "iron_planet" => [50.0, 15.0, 6.0],
+        "jungle_planet" => [85.0, 35.0, 15.0],
+        "mega_earth" => [70.0, 28.0, 11.0],
+        "methane_planet" => [140.0, 65.0, 35.0],
+        "mini_neptune" => [180.0, 90.0, 40.0],
+        "mud_planet" => [110.0, 50.0, 20.0],
+        "protoplanet" => [65.0, 22.0, 9.0],
+        "puffy_planet" => [250.0, 120.0, 60.0],
+        "silicate_clouds_gas_giant" => [205.0, 102.0, 51.0],
+        "steam_giant" => [190.0, 90.0, 40.0],
+        "steam_planet" => [100.0, 45.0, 20.0],
+        "sub_earth" => [85.0, 30.0, 13.0],
+        "subglacial_ocean_planet" => [125.0, 55.0, 28.0],
+        "subsurface_ocean_planet" => [90.0, 40.0, 15.0],
+        "super_earth" => [72.0, 26.0, 10.0],
+        "super_habitable_planet" => [74.0, 24.0, 9.0],
+        "super_io" => [55.0, 16.0, 6.0],
+        "super_mercury" => [58.0, 19.0, 7.0],
+        "super_neptune" => [220.0, 110.0, 55.0],
+        "super_puff" => [260.0, 130.0, 65.0],
+        "tar_planet" => [100.0, 30.0, 10.0],
+        "terrestrial_giant" => [68.0, 27.0, 10.0],
+        "tidally_locked_planet" => [100.0, 35.0, 12.0],
+        "tundra_planet" => [115.0, 45.0, 22.0],
+        "water_giant" => [160.0, 70.0, 30.0],
+        _ => DEFAULT_NOISE_LAYERS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::SURFACE_LEVEL;
+
+    fn material(abundance: f32) -> PlanetMaterial {
+        PlanetMaterial { symbol: "Fe".to_string(), abundance }
+    }
+
+    #[test]
+    fn test_permutation_table_is_a_permutation() {
+        for seed in [0.0, 0.25, 0.5, 0.999_999] {
+            let noise = SimplexNoise::new(seed);
+            let mut seen = [false; 256];
+            for &value in &noise.perm[..256] {
+                assert!(!seen[value as usize], "seed {} repeats {}", seed, value);
+                seen[value as usize] = true;
+            }
+            assert_eq!(noise.perm[..256], noise.perm[256..]);
+        }
+    }
+
+    #[test]
+    fn test_seed_zero_keeps_identity_permutation() {
+        // With random() == 0 every swap is with itself
+        let noise = SimplexNoise::new(0.0);
+        for (i, &value) in noise.perm.iter().enumerate() {
+            assert_eq!(value as usize, i % 256);
+        }
+    }
+
+    #[test]
+    fn test_noise_is_deterministic_and_bounded() {
+        let a = SimplexNoise::new(0.42);
+        let b = SimplexNoise::new(0.42);
+        let other = SimplexNoise::new(0.43);
+        let mut differs = false;
+
+        for step in 0..500 {
+            let t = step as f64 * 0.37;
+            let (x, y, z) = (t - 90.0, t * 0.5 - 40.0, 17.0 - t * 0.8);
+            let value = a.noise3d(x, y, z);
+            assert_eq!(value, b.noise3d(x, y, z));
+            assert!((-1.0..=1.0).contains(&value), "noise3d({}, {}, {}) = {}", x, y, z, value);
+            differs |= value != other.noise3d(x, y, z);
+        }
+        assert!(differs, "different seeds produced identical noise");
+    }
+
+    #[test]
+    fn test_density_bias_is_solid_below_and_air_above() {
+        let generator = TerrainGenerator::new(0.5, &default_noise_params("terrestrial_planet"), &[], TerrainShape::Flat);
+        for x in [-100.0, 0.0, 37.5, 250.0] {
+            // The noise layers sum to at most 1.75 in magnitude
+            assert!(generator.density(&Vector3 { x, y: -200.0, z: x * 0.5 }) < SURFACE_LEVEL);
+            assert!(generator.density(&Vector3 { x, y: 200.0, z: x * 0.5 }) > SURFACE_LEVEL);
+        }
+    }
+
+    #[test]
+    fn test_spherical_density_is_radial() {
+        let generator = TerrainGenerator::new(0.5, &default_noise_params(""), &[], TerrainShape::Sphere(100.0));
+        for direction in [(1.0, 0.0, 0.0), (0.0, -1.0, 0.0), (0.6, 0.0, -0.8), (0.0, 0.6, 0.8)] {
+            let at = |distance: f32| Vector3 { x: direction.0 * distance, y: direction.1 * distance, z: direction.2 * distance };
+            assert!(generator.density(&at(0.0)) < SURFACE_LEVEL);
+            assert!(generator.density(&at(60.0)) < SURFACE_LEVEL);
+            assert!(generator.density(&at(160.0)) > SURFACE_LEVEL);
+        }
+    }
+
+    #[test]
+    fn test_material_follows_palette_abundance() {
+        let sample = Vector3 { x: 12.0, y: -4.0, z: 30.0 };
+
+        let empty = TerrainGenerator::new(0.5, &default_noise_params(""), &[], TerrainShape::Flat);
+        assert_eq!(empty.material(&sample), 0);
+
+        let only_last = TerrainGenerator::new(0.5, &default_noise_params(""), &[material(0.0), material(1.0)], TerrainShape::Flat);
+        let mixed = TerrainGenerator::new(0.5, &default_noise_params(""), &[material(1.0), material(1.0), material(1.0)], TerrainShape::Flat);
+        for step in 0..200 {
+            let sample = Vector3 { x: step as f32 * 3.0, y: -(step as f32), z: step as f32 * 0.5 };
+            assert_eq!(only_last.material(&sample), 1);
+            assert!(mixed.material(&sample) < 3);
+        }
+    }
+
+    #[test]
+    fn test_planet_seed_is_stable_and_in_range() {
+        assert_eq!(planet_seed("ice_planet"), planet_seed("ice_planet"));
+        assert_ne!(planet_seed("ice_planet"), planet_seed("lava_planet"));
+        // The empty name hashes to the FNV-1a offset basis
+        assert_eq!(planet_seed(""), (0xcbf29ce484222325u64 >> 11) as f64 / (1u64 << 53) as f64);
+        for name in ["", "a", "terrestrial_planet", "gas_giant", "unknown"] {
+            let seed = planet_seed(name);
+            assert!((0.0..1.0).contains(&seed));
+        }
+    }
+
+    #[test]
+    fn test_default_noise_params() {
+        let ice = default_noise_params("ice_planet");
+        assert_eq!([ice.base_scale, ice.medium_scale, ice.fine_scale], [120.0, 50.0, 25.0]);
+        let unknown = default_noise_params("not_a_planet");
+        assert_eq!([unknown.base_scale, unknown.medium_scale, unknown.fine_scale], DEFAULT_NOISE_LAYERS);
+    }
+}
@@ -5,36 +5,96 @@
  * and physics calculations.
  * 
  * Key functions:
- * - update_player_position: Calculates new position based on input and delta time
- * - apply_movement: Applies movement vector to player position
- * - calculate_movement_vector: Determines movement direction from input
- * - validate_position: Ensures position is within valid bounds
+ * - update_player_rotation: Applies mouse look as soon as input arrives
+ * - update_player_position: Calculates new movement state based on input and delta time
+ * - local_frame: Up and yaw-zero directions at a position for a planet's movement mode
+ * - yaw_direction: Direction a yaw faces within a local frame
+ * - calculate_movement_vector: Determines movement along the local tangent plane from input
+ * - apply_vertical_physics: Gravity, jumping and terminal velocity
+ * - resolve_capsule: Pushes the player capsule out of the terrain isosurface
+ * - validate_position: Checks a position against the planet's bounds
  * 
  * Movement system:
  * - WASD for directional movement
  * - Shift for sprinting
  * - Mouse for rotation
- * - Space for jumping (future implementation)
+ * - Space for jumping while grounded
  * 
  * Physics considerations:
- * - Delta time-based movement for frame rate independence
+ * - Movement is simulated by game_tick over the real time elapsed since the player was
+ *   last simulated, so speed does not depend on how often clients send input
  * - Speed modifiers for different movement types
- * - Boundary checking to prevent out-of-bounds movement
+ * - Gravity comes from the player's planet; positions are at the feet
+ * - On flat planets up is +Y; on spherical planets up points away from the planet
+ *   center, and yaw and pitch are relative to that local frame; moving carries the
+ *   yaw into the new frame so headings stay continuous
+ * - Vertical velocity, the capsule axis and walkable slopes all follow the local up
+ * - The player is a capsule (PLAYER_RADIUS, PLAYER_HEIGHT) standing on its position
+ * - Terrain is queried through a density callback so this file stays free of database access;
+ *   the distance to the surface is estimated from the density and its gradient
+ * - Bounds come from the planet; callers respawn players who leave them
  */
 
-use crate::common::{Vector3, InputState, PLAYER_SPEED, SPRINT_MULTIPLIER, MOUSE_SENSITIVITY};
+use crate::common::{
+    Vector3, InputState, PLAYER_SPEED, SPRINT_MULTIPLIER, MOUSE_SENSITIVITY, PLAYER_RADIUS, PLAYER_HEIGHT,
+    JUMP_SPEED, TERMINAL_VELOCITY, GROUND_PROBE_DISTANCE, SURFACE_LEVEL, WALKABLE_NORMAL_Y,
+    COLLISION_ITERATIONS, NORMAL_SAMPLE_OFFSET, PlanetBounds, MovementMode,
+    FRAME_POLE_THRESHOLD,
+};
 use std::f32::consts::PI;
 
 // --- Movement Calculations ---
 
-pub fn update_player_position(
-    current_position: &Vector3,
-    current_rotation: &Vector3,
-    input: &InputState,
-    delta_time: f32,
-) -> (Vector3, Vector3) {
-    let mut new_position = current_position.clone();
-    let mut new_rotation = current_rotation.clone();
+#[derive(Clone, Debug)]
+pub struct MovementState {
+    pub position: Vector3,
+    pub rotation: Vector3,
+    pub vertical_velocity: f32, // Positive is up
+    pub grounded: bool,
+}
+
+// Orientation of a player at a position: yaw 0 faces forward, yaw PI / 2 faces right
+#[derive(Clone, Debug)]
+pub struct LocalFrame {
+    pub up: Vector3,
+    pub forward: Vector3,
+    pub right: Vector3,
+}
+
+pub fn local_frame(position: &Vector3, mode: MovementMode) -> LocalFrame {
+    let flat = LocalFrame {
+        up: Vector3 { x: 0.0, y: 1.0, z: 0.0 },
+        forward: Vector3 { x: 0.0, y: 0.0, z: 1.0 },
+        right: Vector3 { x: 1.0, y: 0.0, z: 0.0 },
+    };
+    if mode == MovementMode::Flat {
+        return flat;
+    }
+    let Some(up) = normalize(position) else {
+        return flat;
+    };
+    
+    // Yaw 0 faces +Z projected onto the tangent plane, so the frame matches the flat one at the +Y pole
+    let reference = if up.z.abs() < FRAME_POLE_THRESHOLD { flat.forward.clone() } else { flat.up.clone() };
+    let mut tangent = reference.clone();
+    add_scaled(&mut tangent, &up, -dot(&reference, &up));
+    let forward = normalize(&tangent).unwrap_or(flat.forward);
+    let right = cross(&up, &forward);
+    LocalFrame { up, forward, right }
+}
+
+// Direction of a yaw within the frame's tangent plane
+pub fn yaw_direction(frame: &LocalFrame, yaw: f32) -> Vector3 {
+    let (sin, cos) = yaw.sin_cos();
+    let mut direction = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
+    add_scaled(&mut direction, &frame.forward, cos);
+    add_scaled(&mut direction, &frame.right, sin);
+    direction
+}
+
+// Mouse deltas are per input message, so they are applied once rather than every tick
+pub fn update_player_rotation(rotation: &Vector3, input: &InputState) -> Vector3 {
+    let mut new_rotation = rotation.clone();
     
     // Update rotation based on mouse input
     new_rotation.y += input.mouse_x * MOUSE_SENSITIVITY;
@@ -51,23 +111,146 @@ pub fn update_player_position(
         new_rotation.y += 2.0 * PI;
     }
     
-    // Calculate movement vector
-    let movement_vector = calculate_movement_vector(input, &new_rotation, delta_time);
+    new_rotation
+}
+
+pub fn update_player_position(
+    current: &MovementState,
+    input: &InputState,
+    gravity: f32,
+    mode: MovementMode,
+    delta_time: f32,
+    density: &mut dyn FnMut(&Vector3) -> f32,
+) -> MovementState {
+    // Calculate movement vector in the frame the player starts the tick in
+    let frame = local_frame(&current.position, mode);
+    let mut movement = calculate_movement_vector(input, &current.rotation, &frame, delta_time);
+    
+    // Apply gravity and jumping
+    let mut state = MovementState {
+        position: current.position.clone(),
+        rotation: current.rotation.clone(),
+        vertical_velocity: current.vertical_velocity,
+        grounded: current.grounded,
+    };
+    apply_vertical_physics(&mut state, input.space, gravity, delta_time);
+    add_scaled(&mut movement, &frame.up, state.vertical_velocity * delta_time);
+    
+    // Move in steps no longer than the capsule radius so fast falls cannot tunnel through terrain;
+    // the step count is not capped, speeds and the tick delta already bound the movement length
+    let length = dot(&movement, &movement).sqrt();
+    let steps = if length.is_finite() { (length / PLAYER_RADIUS).ceil().max(1.0) as u32 } else { 0 };
+    let mut contacts = Contacts::default();
+    for _ in 0..steps {
+        add_scaled(&mut state.position, &movement, 1.0 / steps as f32);
+        let up = local_frame(&state.position, mode).up;
+        let step_contacts = resolve_capsule(&mut state.position, &up, density);
+        contacts.ground |= step_contacts.ground;
+        contacts.ceiling |= step_contacts.ceiling;
+    }
+    
+    // Landing stops a fall, bumping the head stops a jump
+    if (contacts.ground && state.vertical_velocity <= 0.0) || (contacts.ceiling && state.vertical_velocity > 0.0) {
+        state.vertical_velocity = 0.0;
+    }
+    // Carry the heading into the new frame so walking straight follows a great circle,
+    // even across the poles of the frame's reference direction
+    let end = local_frame(&state.position, mode);
+    if mode == MovementMode::Spherical {
+        let heading = yaw_direction(&frame, state.rotation.y);
+        state.rotation.y = dot(&heading, &end.right).atan2(dot(&heading, &end.forward));
+    }
     
-    // Apply movement
-    new_position.x += movement_vector.x;
-    new_position.y += movement_vector.y;
-    new_position.z += movement_vector.z;
+    let up = end.up;
+    state.grounded = state.vertical_velocity <= 0.0 && (contacts.ground || is_on_ground(&state.position, &up, density));
     
-    // Validate and clamp position
-    new_position = validate_position(&new_position);
+    state
+}
+
+pub fn apply_vertical_physics(state: &mut MovementState, jump: bool, gravity: f32, delta_time: f32) {
+    if jump && state.grounded {
+        state.vertical_velocity = JUMP_SPEED;
+        state.grounded = false;
+    }
+    state.vertical_velocity = (state.vertical_velocity - gravity * delta_time).max(-TERMINAL_VELOCITY);
+}
+
+// --- Terrain Collision ---
+
+#[derive(Default)]
+struct Contacts {
+    ground: bool,  // Touched a surface walkable from above
+    ceiling: bool, // Touched a surface from below
+}
+
+// Centers of the spheres making up the player capsule, bottom to top along up
+fn capsule_spheres(feet: &Vector3, up: &Vector3) -> [Vector3; 3] {
+    let bottom = PLAYER_RADIUS;
+    let top = PLAYER_HEIGHT - PLAYER_RADIUS;
+    [bottom, (bottom + top) / 2.0, top].map(|height| {
+        let mut center = feet.clone();
+        add_scaled(&mut center, up, height);
+        center
+    })
+}
+
+// Estimated distance from point to the isosurface (negative inside terrain) and the
+// surface normal pointing out of the terrain
+fn surface_distance(point: &Vector3, density: &mut dyn FnMut(&Vector3) -> f32) -> (f32, Vector3) {
+    let value = density(point) - SURFACE_LEVEL;
+    let h = NORMAL_SAMPLE_OFFSET;
+    let mut axis = |dx: f32, dy: f32, dz: f32| {
+        let ahead = density(&Vector3 { x: point.x + dx, y: point.y + dy, z: point.z + dz });
+        let behind = density(&Vector3 { x: point.x - dx, y: point.y - dy, z: point.z - dz });
+        (ahead - behind) / (2.0 * h)
+    };
+    let gradient = Vector3 { x: axis(h, 0.0, 0.0), y: axis(0.0, h, 0.0), z: axis(0.0, 0.0, h) };
     
-    (new_position, new_rotation)
+    let length = (gradient.x * gradient.x + gradient.y * gradient.y + gradient.z * gradient.z).sqrt();
+    if !length.is_finite() || length < 1e-6 {
+        return (value, Vector3 { x: 0.0, y: 1.0, z: 0.0 });
+    }
+    // Density grows towards open space, so the gradient points out of the terrain
+    (value / length, Vector3 { x: gradient.x / length, y: gradient.y / length, z: gradient.z / length })
+}
+
+// Pushes the capsule standing at feet out of the terrain and reports which way it was pushed
+fn resolve_capsule(feet: &mut Vector3, up: &Vector3, density: &mut dyn FnMut(&Vector3) -> f32) -> Contacts {
+    let mut contacts = Contacts::default();
+    for _ in 0..COLLISION_ITERATIONS {
+        let mut pushed = false;
+        for center in capsule_spheres(feet, up) {
+            let (distance, normal) = surface_distance(&center, density);
+            if distance >= PLAYER_RADIUS {
+                continue;
+            }
+
+            let push = (PLAYER_RADIUS - distance).min(PLAYER_HEIGHT);
+            add_scaled(feet, &normal, push);
+            contacts.ground |= dot(&normal, up) >= WALKABLE_NORMAL_Y;
+            contacts.ceiling |= dot(&normal, up) <= -WALKABLE_NORMAL_Y;
+            pushed = true;
+            break;
+        }
+        if !pushed {
+            break;
+        }
+    }
+    contacts
+}
+
+// True if walkable terrain lies within GROUND_PROBE_DISTANCE below the capsule
+fn is_on_ground(feet: &Vector3, up: &Vector3, density: &mut dyn FnMut(&Vector3) -> f32) -> bool {
+    let mut bottom = feet.clone();
+    add_scaled(&mut bottom, up, PLAYER_RADIUS - GROUND_PROBE_DISTANCE);
+    let (distance, normal) = surface_distance(&bottom, density);
+    distance < PLAYER_RADIUS && dot(&normal, up) >= WALKABLE_NORMAL_Y
 }
 
 pub fn calculate_movement_vector(
     input: &InputState,
     rotation: &Vector3,
+    frame: &LocalFrame,
     delta_time: f32,
 ) -> Vector3 {
     let mut movement = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
@@ -83,49 +266,35 @@ pub fn calculate_movement_vector(
     
     // Calculate forward/backward movement
     if input.w {
-        movement.x += rotation.y.sin() * speed;
-        movement.z += rotation.y.cos() * speed;
+        add_scaled(&mut movement, &yaw_direction(frame, rotation.y), speed);
     }
     if input.s {
-        movement.x -= rotation.y.sin() * speed;
-        movement.z -= rotation.y.cos() * speed;
+        add_scaled(&mut movement, &yaw_direction(frame, rotation.y), -speed);
     }
     
     // Calculate left/right movement (strafe)
     if input.a {
-        movement.x += (rotation.y - PI / 2.0).sin() * speed;
-        movement.z += (rotation.y - PI / 2.0).cos() * speed;
+        add_scaled(&mut movement, &yaw_direction(frame, rotation.y - PI / 2.0), speed);
     }
     if input.d {
-        movement.x += (rotation.y + PI / 2.0).sin() * speed;
-        movement.z += (rotation.y + PI / 2.0).cos() * speed;
-    }
-    
-    // Jump movement (simple vertical movement for now)
-    if input.space {
-        movement.y += speed;
+        add_scaled(&mut movement, &yaw_direction(frame, rotation.y + PI / 2.0), speed);
     }
     
+    // Vertical movement comes from apply_vertical_physics
     movement
 }
 
-pub fn validate_position(position: &Vector3) -> Vector3 {
-    let mut validated = position.clone();
-    
-    // Define world boundaries (adjust as needed)
-    const MAX_X: f32 = 1000.0;
-    const MIN_X: f32 = -1000.0;
-    const MAX_Y: f32 = 100.0;
-    const MIN_Y: f32 = -10.0;
-    const MAX_Z: f32 = 1000.0;
-    const MIN_Z: f32 = -1000.0;
-    
-    // Clamp position to boundaries
-    validated.x = validated.x.clamp(MIN_X, MAX_X);
-    validated.y = validated.y.clamp(MIN_Y, MAX_Y);
-    validated.z = validated.z.clamp(MIN_Z, MAX_Z);
+pub fn validate_position(position: &Vector3, bounds: &PlanetBounds) -> bool {
+    if !(position.x.is_finite() && position.y.is_finite() && position.z.is_finite()) {
+        return false;
+    }
     
-    validated
+    match bounds {
+        PlanetBounds::Unbounded => true,
+        PlanetBounds::Sphere(radius) => {
+            calculate_distance(position, &Vector3 { x: 0.0, y: 0.0, z: 0.0 }) <= *radius
+        }
+    }
 }
 
 // --- Utility Functions ---
@@ -137,6 +306,30 @@ pub fn calculate_distance(pos1: &Vector3, pos2: &Vector3) -> f32 {
     (dx * dx + dy * dy + dz * dz).sqrt()
 }
 
+fn dot(a: &Vector3, b: &Vector3) -> f32 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+fn cross(a: &Vector3, b: &Vector3) -> Vector3 {
+    Vector3 { x: a.y * b.z - a.z * b.y, y: a.z * b.x - a.x * b.z, z: a.x * b.y - a.y * b.x }
+}
+
+// Unit vector in the direction of v; None for zero or non-finite vectors
+fn normalize(v: &Vector3) -> Option<Vector3> {
+    let length = dot(v, v).sqrt();
+    if !length.is_finite() || length < 1e-6 {
+        return None;
+    }
+    Some(Vector3 { x: v.x / length, y: v.y / length, z: v.z / length })
+}
+
+// Moves v by amount along direction
+fn add_scaled(v: &mut Vector3, direction: &Vector3, amount: f32) {
+    v.x += direction.x * amount;
+    v.y += direction.y * amount;
+    v.z += direction.z * amount;
+}
+
 pub fn is_significant_movement(old_pos: &Vector3, new_pos: &Vector3, threshold: f32) -> bool {
     calculate_distance(old_pos, new_pos) > threshold
 }
@@ -146,4 +339,155 @@ pub fn is_significant_rotation(old_rot: &Vector3, new_rot: &Vector3, threshold:
     let dy = (old_rot.y - new_rot.y).abs();
     let dz = (old_rot.z - new_rot.z).abs();
     dx > threshold || dy > threshold || dz > threshold
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::MAX_TICK_DELTA_SECS;
+
+    fn idle_input() -> InputState {
+        InputState {
+            w: false, s: false, a: false, d: false, space: false, shift: false,
+            mouse_x: 0.0, mouse_y: 0.0, left_click: false, right_click: false, sequence: 0,
+        }
+    }
+
+    #[test]
+    fn test_fall_at_terminal_velocity_does_not_tunnel() {
+        // A floor 0.5 units thick with its top at y = 0
+        let mut floor = |p: &Vector3| (p.y + 0.25).abs() - 0.25;
+        let falling = MovementState {
+            position: Vector3 { x: 0.0, y: 6.0, z: 0.0 },
+            rotation: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            vertical_velocity: -TERMINAL_VELOCITY,
+            grounded: false,
+        };
+        let state = update_player_position(&falling, &idle_input(), 9.81, MovementMode::Flat, MAX_TICK_DELTA_SECS, &mut floor);
+        assert!(state.position.y > -0.1, "fell through the floor to y = {}", state.position.y);
+        assert!(state.grounded);
+        assert_eq!(state.vertical_velocity, 0.0);
+    }
+
+    #[test]
+    fn test_sprint_into_thin_wall_does_not_tunnel() {
+        // A wall 0.3 units thick at z = 2, crossed without gravity
+        let mut terrain = |p: &Vector3| (p.z - 2.15).abs() - 0.15;
+        let mut input = idle_input();
+        input.w = true;
+        input.shift = true;
+        let mut state = MovementState {
+            position: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            rotation: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            vertical_velocity: 0.0,
+            grounded: true,
+        };
+        for _ in 0..4 {
+            state = update_player_position(&state, &input, 0.0, MovementMode::Flat, MAX_TICK_DELTA_SECS, &mut terrain);
+        }
+        assert!(state.position.z < 2.0, "walked through the wall to z = {}", state.position.z);
+    }
+
+    #[test]
+    fn test_jump_lands_back_on_the_ground() {
+        // Flat ground with its surface at y = 0
+        let mut ground = |p: &Vector3| p.y;
+        let mut input = idle_input();
+        input.space = true;
+        let mut state = MovementState {
+            position: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            rotation: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            vertical_velocity: 0.0,
+            grounded: true,
+        };
+
+        state = update_player_position(&state, &input, 9.81, MovementMode::Flat, 0.05, &mut ground);
+        assert!(!state.grounded);
+        assert!(state.vertical_velocity > 0.0);
+
+        let mut peak: f32 = 0.0;
+        for _ in 0..100 {
+            state = update_player_position(&state, &idle_input(), 9.81, MovementMode::Flat, 0.05, &mut ground);
+            peak = peak.max(state.position.y);
+        }
+        let expected_peak = JUMP_SPEED * JUMP_SPEED / (2.0 * 9.81);
+        assert!((peak - expected_peak).abs() < 0.5, "peaked at y = {}, expected {}", peak, expected_peak);
+        assert!(state.grounded);
+        assert!(state.position.y.abs() < 0.1, "landed at y = {}", state.position.y);
+        assert_eq!(state.vertical_velocity, 0.0);
+    }
+
+    #[test]
+    fn test_falling_speed_is_capped_at_terminal_velocity() {
+        let mut state = MovementState {
+            position: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            rotation: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            vertical_velocity: 0.0,
+            grounded: false,
+        };
+        apply_vertical_physics(&mut state, false, 9.81, 60.0);
+        assert_eq!(state.vertical_velocity, -TERMINAL_VELOCITY);
+
+        // Free fall through open space never moves faster than terminal velocity
+        let mut open = |_: &Vector3| 1000.0;
+        for _ in 0..100 {
+            let before = state.position.y;
+            state = update_player_position(&state, &idle_input(), 9.81, MovementMode::Flat, MAX_TICK_DELTA_SECS, &mut open);
+            assert!(state.vertical_velocity >= -TERMINAL_VELOCITY);
+            assert!(before - state.position.y <= TERMINAL_VELOCITY * MAX_TICK_DELTA_SECS + 1e-3);
+        }
+        assert_eq!(state.vertical_velocity, -TERMINAL_VELOCITY);
+    }
+
+    #[test]
+    fn test_leaving_sphere_bounds_triggers_respawn() {
+        // Callers respawn players whose position fails validation
+        let bounds = PlanetBounds::Sphere(20.0);
+        let mut open = |_: &Vector3| 1000.0;
+        let mut input = idle_input();
+        input.w = true;
+        let mut state = MovementState {
+            position: Vector3 { x: 0.0, y: 0.0, z: 15.0 },
+            rotation: Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            vertical_velocity: 0.0,
+            grounded: true,
+        };
+        assert!(validate_position(&state.position, &bounds));
+
+        for _ in 0..4 {
+            state = update_player_position(&state, &input, 0.0, MovementMode::Flat, MAX_TICK_DELTA_SECS, &mut open);
+        }
+        assert!(state.position.z > 20.0);
+        assert!(!validate_position(&state.position, &bounds));
+        assert!(validate_position(&state.position, &PlanetBounds::Unbounded));
+        assert!(!validate_position(&Vector3 { x: f32::NAN, y: 0.0, z: 0.0 }, &PlanetBounds::Unbounded));
+    }
+
+    #[test]
+    fn test_spherical_local_frame_is_orthonormal_and_points_away_from_center() {
+        let positions = [
+            Vector3 { x: 0.0, y: 100.0, z: 0.0 },
+            Vector3 { x: 30.0, y: -40.0, z: 12.0 },
+            Vector3 { x: -5.0, y: 2.0, z: -80.0 },
+            Vector3 { x: 0.0, y: 0.0, z: 50.0 }, // On the +Z pole of the yaw reference
+        ];
+        for position in &positions {
+            let frame = local_frame(position, MovementMode::Spherical);
+            for axis in [&frame.up, &frame.forward, &frame.right] {
+                assert!((dot(axis, axis) - 1.0).abs() < 1e-5);
+            }
+            assert!(dot(&frame.up, &frame.forward).abs() < 1e-5);
+            assert!(dot(&frame.up, &frame.right).abs() < 1e-5);
+            assert!(dot(&frame.forward, &frame.right).abs() < 1e-5);
+
+            let outward = normalize(position).unwrap();
+            assert!((dot(&frame.up, &outward) - 1.0).abs() < 1e-5);
+        }
+
+        // At the +Y pole the spherical frame matches the flat one
+        let pole = local_frame(&positions[0], MovementMode::Spherical);
+        let flat = local_frame(&positions[0], MovementMode::Flat);
+        assert!((dot(&pole.forward, &flat.forward) - 1.0).abs() < 1e-5);
+        assert!((dot(&pole.right, &flat.right) - 1.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * Infinia Multiplayer - survival.rs
+ *
+ * This file contains the hunger, thirst and stamina rules applied by
+ * game_tick and the consume_item reducer.
+ *
+ * Key functions:
+ * - SurvivalStats::full: Stats of a new player
+ * - SurvivalStats::decay: Hunger/thirst decay and stamina drain/recovery over time
+ * - SurvivalStats::depleted_count: Number of depleted stats that hurt the player
+ * - SurvivalStats::restore: Applies a consumed item's effect
+ * - item_effect: What consuming an item restores
+ *
+ * Survival rules:
+ * - Hunger and thirst decay at the rates of debug/modules/wilderness/hungerThirstSystem.ts,
+ *   faster while sprinting
+ * - Sprinting drains stamina; it recovers while not sprinting, and players
+ *   cannot sprint with no stamina
+ * - Each depleted stat (hunger or thirst at 0) deals DEPLETION_DAMAGE_PER_SEC and
+ *   stops health regeneration
+ *
+ * When modifying:
+ * - All stats range from 0 to MAX_SURVIVAL_STAT
+ */
+
+use crate::common::{
+    SurvivalStats, MAX_SURVIVAL_STAT, HUNGER_DECAY_PER_SEC, THIRST_DECAY_PER_SEC, SPRINT_DECAY_MULTIPLIER,
+    STAMINA_SPRINT_DRAIN_PER_SEC, STAMINA_RECOVERY_PER_SEC,
+};
+
+impl SurvivalStats {
+    pub fn full() -> Self {
+        SurvivalStats { hunger: MAX_SURVIVAL_STAT, thirst: MAX_SURVIVAL_STAT, stamina: MAX_SURVIVAL_STAT }
+    }
+
+    pub fn decay(&mut self, sprinting: bool, delta_time: f32) {
+        let multiplier = if sprinting { SPRINT_DECAY_MULTIPLIER } else { 1.0 };
+        self.hunger = (self.hunger - HUNGER_DECAY_PER_SEC * multiplier * delta_time).max(0.0);
+        self.thirst = (self.thirst - THIRST_DECAY_PER_SEC * multiplier * delta_time).max(0.0);
+        self.stamina = if sprinting {
+            (self.stamina - STAMINA_SPRINT_DRAIN_PER_SEC * delta_time).max(0.0)
+        } else {
+            (self.stamina + STAMINA_RECOVERY_PER_SEC * delta_time).min(MAX_SURVIVAL_STAT)
+        };
+    }
+
+    pub fn depleted_count(&self) -> i32 {
+        (self.hunger <= 0.0) as i32 + (self.thirst <= 0.0) as i32
+    }
+
+    pub fn restore(&mut self, effect: &ItemEffect) {
+        self.hunger = (self.hunger + effect.hunger).min(MAX_SURVIVAL_STAT);
+        self.thirst = (self.thirst + effect.thirst).min(MAX_SURVIVAL_STAT);
+        self.stamina = (self.stamina + effect.stamina).min(MAX_SURVIVAL_STAT);
+    }
+}
+
+// --- Consumables ---
+
+pub struct ItemEffect {
+    pub hunger: f32,
+    pub thirst: f32,
+    pub stamina: f32,
+    pub health: i32,
+}
+
+// Effect of consuming one unit of an item; None for items that cannot be consumed
+pub fn item_effect(item_id: &str) -> Option<ItemEffect> {
+    match item_id {
+        "food" => Some(ItemEffect { hunger: 20.0, thirst: 0.0, stamina: 10.0, health: 0 }),
+        "water" => Some(ItemEffect { hunger: 0.0, thirst: 25.0, stamina: 5.0, health: 0 }),
+        "herb" => Some(ItemEffect { hunger: 5.0, thirst: 0.0, stamina: 0.0, health: 5 }),
+        "medicine" => Some(ItemEffect { hunger: 0.0, thirst: 0.0, stamina: 0.0, health: 25 }),
+        _ => None,
+    }
+}
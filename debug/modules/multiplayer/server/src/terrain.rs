@@ -0,0 +1,616 @@
+/*
+ * Infinia Multiplayer - terrain.rs
+ *
+ * This file contains the terrain math shared by the terrain reducers:
+ * chunk/voxel indexing, world-to-chunk conversion, sparse voxel deltas
+ * and brush application.
+ *
+ * Key functions:
+ * - voxel_index: Flattens chunk-local sample coordinates into a noise_data index
+ * - chunk_origin: World position of a chunk's first sample
+ * - world_to_chunk: Chunk coordinates containing a world position
+ * - nearest_sample: Chunk and voxel index of the sample nearest to a world position
+ * - trilinear: Interpolates a density between the eight samples around a position
+ * - chunks_in_view: Chunks within a player's view radius
+ * - chunks_touched_by_brush: Every chunk whose samples a brush can reach
+ * - shared_samples / copy_shared_samples: Keep border samples duplicated between neighbors equal
+ * - generate_base_field / generate_base_materials: Procedural fields a chunk is stored relative to
+ * - diff_against_base / diff_materials / materialize_field: Convert between full fields and sparse deltas
+ * - changed_samples: Number of samples whose stored density or material differs between two delta sets
+ * - apply_brush: Applies a sphere/box brush to a chunk's density and material deltas and
+ *   counts the player-placed material it adds or removes
+ *
+ * Data layout:
+ * - A chunk's full field has CHUNK_SAMPLES^3 densities, indexed [y][z][x] like the client NoiseMap
+ * - Only samples that differ from the generated base are stored, as sorted index/value pairs
+ * - Materials are palette indices into the planet's materials, stored the same way;
+ *   a material delta marks a sample a player placed (or an admin upload changed), so
+ *   samples without one are generated terrain
+ * - Sample (x, y, z) sits at chunk_origin + (x, y, z) in world space
+ * - Neighboring chunks duplicate their shared face/edge/corner samples; the base
+ *   density depends only on world position, so equal deltas mean equal samples
+ * - Densities below SURFACE_LEVEL are solid, so adding terrain lowers density
+ */
+
+use crate::common::{
+    Vector3, ChunkCoord, BrushShape, EditMode,
+    CHUNK_SIZE, CHUNK_SAMPLES, CHUNK_VOXEL_COUNT, DELTA_EPSILON, SURFACE_LEVEL, VIEW_RADIUS_VERTICAL,
+};
+use crate::noise::TerrainGenerator;
+use std::collections::{BTreeMap, BTreeSet};
+
+// Modified samples of a chunk, keyed by voxel_index
+pub type VoxelDeltas = BTreeMap<u32, f32>;
+pub type MaterialDeltas = BTreeMap<u32, u8>;
+
+// Everything stored about a chunk's modifications
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChunkDeltas {
+    pub densities: VoxelDeltas,
+    pub materials: MaterialDeltas,
+}
+
+// Placed material an edit added (paid from the inventory) or removed (refunded), and
+// generated material it carved to air (mined), counted per palette index over the
+// samples each chunk owns
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MaterialChanges {
+    pub placed: BTreeMap<u8, u32>,
+    pub removed: BTreeMap<u8, u32>,
+    pub mined: BTreeMap<u8, u32>,
+}
+
+// --- Brush Definition ---
+
+pub struct Brush {
+    pub center: Vector3,
+    pub shape: BrushShape,
+    pub mode: EditMode,
+    pub strength: f32,
+    pub radius: f32,
+    pub material: u8, // Palette index given to samples an Add brush turns solid
+}
+
+// --- Chunk Indexing ---
+
+pub fn voxel_index(x: usize, y: usize, z: usize) -> usize {
+    (y * CHUNK_SAMPLES + z) * CHUNK_SAMPLES + x
+}
+
+pub fn chunk_origin(coord: &ChunkCoord) -> Vector3 {
+    // X/Z chunks are centred on chunk * CHUNK_SIZE (matching the client's
+    // generateNoiseMap offsets), Y chunks start at chunk * CHUNK_SIZE
+    Vector3 {
+        x: (coord.x * CHUNK_SIZE - CHUNK_SIZE / 2) as f32,
+        y: (coord.y * CHUNK_SIZE) as f32,
+        z: (coord.z * CHUNK_SIZE - CHUNK_SIZE / 2) as f32,
+    }
+}
+
+pub fn sample_position(origin: &Vector3, index: usize) -> Vector3 {
+    let x = index % CHUNK_SAMPLES;
+    let z = (index / CHUNK_SAMPLES) % CHUNK_SAMPLES;
+    let y = index / (CHUNK_SAMPLES * CHUNK_SAMPLES);
+    Vector3 {
+        x: origin.x + x as f32,
+        y: origin.y + y as f32,
+        z: origin.z + z as f32,
+    }
+}
+
+pub fn world_to_chunk(planet_id: u32, position: &Vector3) -> ChunkCoord {
+    let size = CHUNK_SIZE as f32;
+    let half = size / 2.0;
+    ChunkCoord {
+        planet_id,
+        x: ((position.x + half) / size).floor() as i32,
+        y: (position.y / size).floor() as i32,
+        z: ((position.z + half) / size).floor() as i32,
+    }
+}
+
+// Samples sit on integer world coordinates, so the nearest one is the rounded position
+pub fn nearest_sample(planet_id: u32, position: &Vector3) -> (ChunkCoord, u32, Vector3) {
+    let sample = Vector3 { x: position.x.round(), y: position.y.round(), z: position.z.round() };
+    let coord = world_to_chunk(planet_id, &sample);
+    let origin = chunk_origin(&coord);
+    let index = voxel_index(
+        (sample.x - origin.x) as usize,
+        (sample.y - origin.y) as usize,
+        (sample.z - origin.z) as usize,
+    );
+    (coord, index as u32, sample)
+}
+
+// corners are indexed x + 2y + 4z; t is the position within the cell, each axis in [0, 1]
+pub fn trilinear(corners: &[f32; 8], t: &Vector3) -> f32 {
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    let edge = |base: usize, t_x: f32| lerp(corners[base], corners[base + 1], t_x);
+    let near = lerp(edge(0, t.x), edge(2, t.x), t.y);
+    let far = lerp(edge(4, t.x), edge(6, t.x), t.y);
+    lerp(near, far, t.z)
+}
+
+// Box of chunks around center: view_radius on X/Z, VIEW_RADIUS_VERTICAL on Y
+pub fn chunks_in_view(center: &ChunkCoord, view_radius: i32) -> Vec<ChunkCoord> {
+    let mut chunks = Vec::new();
+    for y in -VIEW_RADIUS_VERTICAL..=VIEW_RADIUS_VERTICAL {
+        for z in -view_radius..=view_radius {
+            for x in -view_radius..=view_radius {
+                chunks.push(ChunkCoord {
+                    planet_id: center.planet_id,
+                    x: center.x + x,
+                    y: center.y + y,
+                    z: center.z + z,
+                });
+            }
+        }
+    }
+    chunks
+}
+
+// Chunks whose sample box intersects the brush's bounding box, at any height
+pub fn chunks_touched_by_brush(planet_id: u32, brush: &Brush) -> Vec<ChunkCoord> {
+    let size = CHUNK_SIZE as f32;
+    let half = size / 2.0;
+    let axis = |low: f32, high: f32| {
+        let first = (low / size).ceil() as i32;
+        let last = (high / size).floor() as i32;
+        first..=last
+    };
+
+    // Chunk c spans [c*32 - 16, c*32 + 16] on X/Z and [c*32, c*32 + 32] on Y
+    let (center, radius) = (&brush.center, brush.radius);
+    let mut chunks = Vec::new();
+    for y in axis(center.y - radius - size, center.y + radius) {
+        for z in axis(center.z - radius - half, center.z + radius + half) {
+            for x in axis(center.x - radius - half, center.x + radius + half) {
+                chunks.push(ChunkCoord { planet_id, x, y, z });
+            }
+        }
+    }
+    chunks
+}
+
+// The 26 chunks sharing a face, edge or corner with coord
+pub fn neighbor_coords(coord: &ChunkCoord) -> Vec<ChunkCoord> {
+    let mut neighbors = Vec::with_capacity(26);
+    for y in -1..=1 {
+        for z in -1..=1 {
+            for x in -1..=1 {
+                if (x, y, z) != (0, 0, 0) {
+                    neighbors.push(ChunkCoord {
+                        planet_id: coord.planet_id,
+                        x: coord.x + x,
+                        y: coord.y + y,
+                        z: coord.z + z,
+                    });
+                }
+            }
+        }
+    }
+    neighbors
+}
+
+// Pairs of (index in from, index in to) for samples both chunks contain
+pub fn shared_samples(from: &ChunkCoord, to: &ChunkCoord) -> Vec<(u32, u32)> {
+    let last = CHUNK_SAMPLES - 1;
+    let axis = |offset: i32| -> Vec<(usize, usize)> {
+        match offset {
+            0 => (0..CHUNK_SAMPLES).map(|i| (i, i)).collect(),
+            1 => vec![(last, 0)],
+            -1 => vec![(0, last)],
+            _ => Vec::new(),
+        }
+    };
+
+    if from.planet_id != to.planet_id || from == to {
+        return Vec::new();
+    }
+
+    let xs = axis(to.x - from.x);
+    let ys = axis(to.y - from.y);
+    let zs = axis(to.z - from.z);
+    let mut pairs = Vec::with_capacity(xs.len() * ys.len() * zs.len());
+    for &(from_y, to_y) in &ys {
+        for &(from_z, to_z) in &zs {
+            for &(from_x, to_x) in &xs {
+                pairs.push((
+                    voxel_index(from_x, from_y, from_z) as u32,
+                    voxel_index(to_x, to_y, to_z) as u32,
+                ));
+            }
+        }
+    }
+    pairs
+}
+
+// Makes target's shared samples equal to source's; returns whether target changed
+pub fn copy_shared_samples(source: &ChunkDeltas, target: &mut ChunkDeltas, shared: &[(u32, u32)]) -> bool {
+    let densities = copy_shared(&source.densities, &mut target.densities, shared);
+    let materials = copy_shared(&source.materials, &mut target.materials, shared);
+    densities || materials
+}
+
+fn copy_shared<T: Copy + PartialEq>(
+    source: &BTreeMap<u32, T>,
+    target: &mut BTreeMap<u32, T>,
+    shared: &[(u32, u32)],
+) -> bool {
+    let mut changed = false;
+    for &(source_index, target_index) in shared {
+        let value = source.get(&source_index).copied();
+        if target.get(&target_index).copied() == value {
+            continue;
+        }
+        match value {
+            Some(value) => target.insert(target_index, value),
+            None => target.remove(&target_index),
+        };
+        changed = true;
+    }
+    changed
+}
+
+// --- Procedural Base and Deltas ---
+
+pub fn generate_base_field(generator: &TerrainGenerator, origin: &Vector3) -> Vec<f32> {
+    (0..CHUNK_VOXEL_COUNT)
+        .map(|index| generator.density(&sample_position(origin, index)))
+        .collect()
+}
+
+// Samples within tolerance of the base (at least DELTA_EPSILON) count as unmodified
+pub fn diff_against_base(base: &[f32], field: &[f32], tolerance: f32) -> VoxelDeltas {
+    let tolerance = tolerance.max(DELTA_EPSILON);
+    base.iter()
+        .zip(field.iter())
+        .enumerate()
+        .filter(|(_, (base_value, value))| (*value - *base_value).abs() > tolerance)
+        .map(|(index, (_, value))| (index as u32, *value))
+        .collect()
+}
+
+pub fn generate_base_materials(generator: &TerrainGenerator, origin: &Vector3) -> Vec<u8> {
+    (0..CHUNK_VOXEL_COUNT)
+        .map(|index| generator.material(&sample_position(origin, index)))
+        .collect()
+}
+
+pub fn diff_materials(base: &[u8], field: &[u8]) -> MaterialDeltas {
+    base.iter()
+        .zip(field.iter())
+        .enumerate()
+        .filter(|(_, (base_value, value))| base_value != value)
+        .map(|(index, (_, value))| (index as u32, *value))
+        .collect()
+}
+
+pub fn changed_samples(before: &ChunkDeltas, after: &ChunkDeltas) -> u32 {
+    let changed = |index: &u32| {
+        before.densities.get(index) != after.densities.get(index)
+            || before.materials.get(index) != after.materials.get(index)
+    };
+    let indices: BTreeSet<&u32> = before.densities.keys()
+        .chain(after.densities.keys())
+        .chain(before.materials.keys())
+        .chain(after.materials.keys())
+        .collect();
+    indices.into_iter().filter(|index| changed(index)).count() as u32
+}
+
+pub fn materialize_field<T: Copy>(mut base: Vec<T>, deltas: &BTreeMap<u32, T>) -> Vec<T> {
+    for (&index, &value) in deltas {
+        if let Some(sample) = base.get_mut(index as usize) {
+            *sample = value;
+        }
+    }
+    base
+}
+
+// --- Brush Application ---
+
+pub fn brush_falloff(brush: &Brush, sample: &Vector3) -> f32 {
+    let dx = sample.x - brush.center.x;
+    let dy = sample.y - brush.center.y;
+    let dz = sample.z - brush.center.z;
+
+    match brush.shape {
+        BrushShape::Sphere => {
+            let distance = (dx * dx + dy * dy + dz * dz).sqrt();
+            if distance >= brush.radius {
+                0.0
+            } else {
+                1.0 - distance / brush.radius
+            }
+        }
+        BrushShape::Box => {
+            if dx.abs() <= brush.radius && dy.abs() <= brush.radius && dz.abs() <= brush.radius {
+                1.0
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+// Range of sample indices along one axis that a brush can touch
+fn sample_range(origin: f32, center: f32, radius: f32) -> std::ops::RangeInclusive<usize> {
+    let max_index = (CHUNK_SAMPLES - 1) as f32;
+    let start = (center - radius - origin).floor().clamp(0.0, max_index) as usize;
+    let end = (center + radius - origin).ceil().clamp(0.0, max_index) as usize;
+    start..=end
+}
+
+pub fn apply_brush(
+    deltas: &mut ChunkDeltas,
+    origin: &Vector3,
+    brush: &Brush,
+    generator: &TerrainGenerator,
+    changes: &mut MaterialChanges,
+) -> u32 {
+    let mut modified = 0;
+
+    // Adding terrain makes samples more solid (lower density)
+    let sign = match brush.mode {
+        EditMode::Add => -1.0,
+        EditMode::Subtract => 1.0,
+    };
+
+    for y in sample_range(origin.y, brush.center.y, brush.radius) {
+        for z in sample_range(origin.z, brush.center.z, brush.radius) {
+            for x in sample_range(origin.x, brush.center.x, brush.radius) {
+                let sample = Vector3 {
+                    x: origin.x + x as f32,
+                    y: origin.y + y as f32,
+                    z: origin.z + z as f32,
+                };
+
+                let falloff = brush_falloff(brush, &sample);
+                if falloff <= 0.0 {
+                    continue;
+                }
+
+                let index = voxel_index(x, y, z) as u32;
+                let base = generator.density(&sample);
+                let current = deltas.densities.get(&index).copied().unwrap_or(base);
+                let value = current + sign * brush.strength * falloff;
+
+                // Samples edited back to the procedural base no longer need storing
+                if (value - base).abs() > DELTA_EPSILON {
+                    deltas.densities.insert(index, value);
+                } else {
+                    deltas.densities.remove(&index);
+                }
+
+                // Samples an Add brush turns solid are recorded as placed with the brush
+                // material; existing solid keeps its own, so mined resources stay where they
+                // were generated. Placed samples carved back to air drop their record, and
+                // generated ones count as mined only when they cross the surface, so making
+                // solid terrain denser never produces anything to mine.
+                // Border samples are counted only by the chunk that owns them.
+                let owned = x < CHUNK_SIZE as usize && y < CHUNK_SIZE as usize && z < CHUNK_SIZE as usize;
+                if brush.mode == EditMode::Add && current >= SURFACE_LEVEL && value < SURFACE_LEVEL {
+                    deltas.materials.insert(index, brush.material);
+                    if owned {
+                        *changes.placed.entry(brush.material).or_insert(0) += 1;
+                    }
+                } else if brush.mode == EditMode::Subtract && current < SURFACE_LEVEL && value >= SURFACE_LEVEL {
+                    let carved = match deltas.materials.remove(&index) {
+                        Some(material) => changes.removed.entry(material),
+                        None => changes.mined.entry(generator.material(&sample)),
+                    };
+                    if owned {
+                        *carved.or_insert(0) += 1;
+                    }
+                }
+                modified += 1;
+            }
+        }
+    }
+
+    modified
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{MAX_BRUSH_STRENGTH, MINING_RADIUS};
+    use crate::noise::TerrainShape;
+
+    fn coord(x: i32, y: i32, z: i32) -> ChunkCoord {
+        ChunkCoord { planet_id: 1, x, y, z }
+    }
+
+    fn brush(center: Vector3, radius: f32) -> Brush {
+        Brush { center, shape: BrushShape::Box, mode: EditMode::Add, strength: 1.0, radius, material: 0 }
+    }
+
+    // Chunk c holds samples [c*32 - 16, c*32 + 16] on X/Z and [c*32, c*32 + 32] on Y
+    fn chunk_contains(coord: &ChunkCoord, sample: &Vector3) -> bool {
+        let origin = chunk_origin(coord);
+        let span = CHUNK_SIZE as f32;
+        [(sample.x, origin.x), (sample.y, origin.y), (sample.z, origin.z)]
+            .iter()
+            .all(|&(value, low)| value >= low && value <= low + span)
+    }
+
+    #[test]
+    fn test_nearest_sample_is_owned_by_its_chunk() {
+        let positions = [
+            Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+            Vector3 { x: 16.0, y: 32.0, z: -16.0 },
+            Vector3 { x: -16.4, y: 31.6, z: 15.5 },
+            Vector3 { x: -100.7, y: -0.2, z: 47.49 },
+            Vector3 { x: 1000.5, y: -33.5, z: -1000.5 },
+        ];
+        for position in positions {
+            let (coord, index, sample) = nearest_sample(1, &position);
+            assert_eq!(sample.x, position.x.round());
+            assert_eq!(sample.y, position.y.round());
+            assert_eq!(sample.z, position.z.round());
+
+            // The owning chunk stores the sample below its upper border
+            let origin = chunk_origin(&coord);
+            for local in [sample.x - origin.x, sample.y - origin.y, sample.z - origin.z] {
+                assert!((0.0..CHUNK_SIZE as f32).contains(&local), "{:?} in {:?}", sample, coord);
+            }
+            assert_eq!(sample_position(&origin, index as usize), sample);
+        }
+    }
+
+    #[test]
+    fn test_nearest_sample_on_negative_border() {
+        // x = -16 is the first sample of chunk 0, not the last sample of chunk -1
+        let (owner, index, _) = nearest_sample(1, &Vector3 { x: -16.0, y: -1.0, z: 16.0 });
+        assert_eq!(owner, coord(0, -1, 1));
+        assert_eq!(index as usize, voxel_index(0, 31, 0));
+    }
+
+    #[test]
+    fn test_chunks_touched_by_brush_covers_every_sample() {
+        let brushes = [
+            brush(Vector3 { x: 0.0, y: 0.0, z: 0.0 }, 3.0),
+            brush(Vector3 { x: 16.0, y: 32.0, z: -16.0 }, 2.0),
+            brush(Vector3 { x: -47.3, y: -5.5, z: 80.1 }, 6.5),
+        ];
+        for brush in brushes {
+            let touched = chunks_touched_by_brush(1, &brush);
+            let (center, radius) = (&brush.center, brush.radius);
+            let range = |value: f32| (value - radius).ceil() as i32..=(value + radius).floor() as i32;
+
+            // Every chunk holding a sample inside the brush box is returned
+            for y in range(center.y) {
+                for z in range(center.z) {
+                    for x in range(center.x) {
+                        let sample = Vector3 { x: x as f32, y: y as f32, z: z as f32 };
+                        let owner = world_to_chunk(1, &sample);
+                        for candidate in std::iter::once(owner).chain(neighbor_coords(&owner)) {
+                            if chunk_contains(&candidate, &sample) {
+                                assert!(touched.contains(&candidate), "{:?} misses {:?}", sample, candidate);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // And no returned chunk lies entirely outside it
+            for chunk in &touched {
+                let origin = chunk_origin(chunk);
+                let span = CHUNK_SIZE as f32;
+                for (low, value) in [(origin.x, center.x), (origin.y, center.y), (origin.z, center.z)] {
+                    assert!(value + radius >= low && value - radius <= low + span, "{:?}", chunk);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shared_samples_match_world_positions() {
+        let from = coord(0, 0, 0);
+        let from_origin = chunk_origin(&from);
+        for to in neighbor_coords(&from) {
+            let to_origin = chunk_origin(&to);
+            let pairs = shared_samples(&from, &to);
+
+            let shared_axes = [to.x, to.y, to.z].iter().filter(|&&offset| offset == 0).count() as u32;
+            assert_eq!(pairs.len(), CHUNK_SAMPLES.pow(shared_axes));
+            for &(from_index, to_index) in &pairs {
+                assert_eq!(
+                    sample_position(&from_origin, from_index as usize),
+                    sample_position(&to_origin, to_index as usize)
+                );
+            }
+
+            let reversed: Vec<(u32, u32)> = shared_samples(&to, &from).iter().map(|&(a, b)| (b, a)).collect();
+            assert_eq!(reversed, pairs);
+        }
+    }
+
+    #[test]
+    fn test_apply_brush_counts_placed_material_once_per_sample() {
+        let generator = TerrainGenerator::new(0.5, &crate::noise::default_noise_params(""), &[], TerrainShape::Flat);
+        // High above the y = 10 surface everything is air; the brush straddles the x = 16 border
+        let mut add = brush(Vector3 { x: 16.0, y: 300.0, z: 0.0 }, 1.0);
+        add.strength = 100.0;
+        add.material = 3;
+
+        let chunks = [coord(0, 9, 0), coord(1, 9, 0)];
+        let mut all_deltas = vec![ChunkDeltas::default(); chunks.len()];
+        let mut changes = MaterialChanges::default();
+        for (chunk, deltas) in chunks.iter().zip(all_deltas.iter_mut()) {
+            apply_brush(deltas, &chunk_origin(chunk), &add, &generator, &mut changes);
+        }
+        assert_eq!(changes.placed, [(3, 27)].into_iter().collect());
+        assert!(all_deltas.iter().all(|deltas| deltas.materials.values().all(|&material| material == 3)));
+
+        let subtract = Brush { mode: EditMode::Subtract, ..add };
+        let mut changes = MaterialChanges::default();
+        for (chunk, deltas) in chunks.iter().zip(all_deltas.iter_mut()) {
+            apply_brush(deltas, &chunk_origin(chunk), &subtract, &generator, &mut changes);
+        }
+        assert!(changes.placed.is_empty());
+        assert_eq!(changes.removed, [(3, 27)].into_iter().collect());
+        assert!(all_deltas.iter().all(|deltas| deltas.materials.is_empty()));
+    }
+
+    #[test]
+    fn test_adding_then_mining_solid_terrain_yields_nothing() {
+        let generator = TerrainGenerator::new(0.5, &crate::noise::default_noise_params(""), &[], TerrainShape::Flat);
+        let chunk = coord(0, 0, 0);
+        let origin = chunk_origin(&chunk);
+        let mut deltas = ChunkDeltas::default();
+
+        // Below the y = 10 surface, so the mining brush only touches generated solid
+        let stroke = |mode| Brush {
+            shape: BrushShape::Sphere,
+            mode,
+            strength: MAX_BRUSH_STRENGTH,
+            ..brush(Vector3 { x: 16.0, y: 6.0, z: 16.0 }, MINING_RADIUS)
+        };
+        let (add, mine) = (stroke(EditMode::Add), stroke(EditMode::Subtract));
+
+        // Adding to solid terrain only makes it denser; mining it back never crosses the surface
+        let mut changes = MaterialChanges::default();
+        for _ in 0..20 {
+            apply_brush(&mut deltas, &origin, &add, &generator, &mut changes);
+            apply_brush(&mut deltas, &origin, &mine, &generator, &mut changes);
+        }
+        assert_eq!(changes, MaterialChanges::default());
+
+        // Mining on its own yields each generated sample once, when it turns to air
+        let mut changes = MaterialChanges::default();
+        for _ in 0..100 {
+            apply_brush(&mut deltas, &origin, &mine, &generator, &mut changes);
+        }
+        let mined: u32 = changes.mined.values().sum();
+        assert!(mined > 0);
+        assert!(changes.placed.is_empty() && changes.removed.is_empty());
+
+        let mut again = MaterialChanges::default();
+        apply_brush(&mut deltas, &origin, &mine, &generator, &mut again);
+        assert_eq!(again, MaterialChanges::default());
+    }
+
+    #[test]
+    fn test_changed_samples_counts_each_index_once() {
+        let before = ChunkDeltas {
+            densities: [(1, -2.0), (2, 3.0), (5, 1.0)].into_iter().collect(),
+            materials: [(1, 4)].into_iter().collect(),
+        };
+        let after = ChunkDeltas {
+            densities: [(1, -1.0), (2, 3.0), (7, 1.0)].into_iter().collect(),
+            materials: [(1, 2), (2, 1)].into_iter().collect(),
+        };
+        // 1 changes both fields, 2 gains a material, 5 is removed and 7 is added
+        assert_eq!(changed_samples(&before, &after), 4);
+        assert_eq!(changed_samples(&after, &after), 0);
+    }
+
+    #[test]
+    fn test_shared_samples_requires_adjacent_chunks() {
+        assert!(shared_samples(&coord(0, 0, 0), &coord(0, 0, 0)).is_empty());
+        assert!(shared_samples(&coord(0, 0, 0), &coord(2, 0, 0)).is_empty());
+        assert!(shared_samples(&coord(0, 0, 0), &ChunkCoord { planet_id: 2, x: 1, y: 0, z: 0 }).is_empty());
+    }
+}
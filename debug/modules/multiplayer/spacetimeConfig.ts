@@ -3,7 +3,7 @@ import { Identity, ConnectionId } from '@clockworklabs/spacetimedb-sdk';
 import { DbConnection, connectToDatabase, getConnection, getDatabase, disconnect } from './generated/connection';
 import { DatabaseInterface } from './generated/tables';
 import { registerPlayer, updatePlayerInput, storeTerrainChunk, getTerrainChunk, storeInitialChunksForPlanet } from './generated/reducers';
-import { PlayerData, LoggedOutPlayerData, Vector3, InputState, GameTickSchedule, TerrainChunk } from './generated/types';
+import { PlayerData, LoggedOutPlayerData, Vector3, InputState, GameTickSchedule, TerrainChunk, ChunkRequest, ChunkCoord, ChunkEncoding } from './generated/types';
 
 // SpacetimeDB configuration
 export const SPACETIMEDB_URL = 'ws://localhost:3000';
@@ -18,6 +18,9 @@ export type {
     InputState,
     GameTickSchedule,
     TerrainChunk,
+    ChunkRequest,
+    ChunkCoord,
+    ChunkEncoding,
     DatabaseInterface
 };
 
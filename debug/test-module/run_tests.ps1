@@ -25,7 +25,7 @@ if ($LASTEXITCODE -eq 0) {
 
 # Test 3: Store Terrain Chunk
 Write-Host "`nTest 3: Testing store_terrain_chunk reducer..." -ForegroundColor Yellow
-$result3 = spacetime call testmodule2 store_terrain_chunk '"test_chunk_5_10_15"' '"earth"' 5 10 15 --anonymous
+$result3 = spacetime call testmodule2 store_terrain_chunk '{"planet_id": 1, "x": 5, "y": 10, "z": 15}' --anonymous
 if ($LASTEXITCODE -eq 0) {
     Write-Host "[PASS] store_terrain_chunk test PASSED" -ForegroundColor Green
 } else {
@@ -34,7 +34,7 @@ if ($LASTEXITCODE -eq 0) {
 
 # Test 4: Get Terrain Chunk
 Write-Host "`nTest 4: Testing get_terrain_chunk reducer..." -ForegroundColor Yellow
-$result4 = spacetime call testmodule2 get_terrain_chunk '"test_chunk_5_10_15"' --anonymous
+$result4 = spacetime call testmodule2 get_terrain_chunk '{"planet_id": 1, "x": 5, "y": 10, "z": 15}' --anonymous
 if ($LASTEXITCODE -eq 0) {
     Write-Host "[PASS] get_terrain_chunk test PASSED" -ForegroundColor Green
 } else {
@@ -43,7 +43,7 @@ if ($LASTEXITCODE -eq 0) {
 
 # Test 5: Store Initial Chunks for Planet
 Write-Host "`nTest 5: Testing store_initial_chunks_for_planet reducer..." -ForegroundColor Yellow
-$result5 = spacetime call testmodule2 store_initial_chunks_for_planet '"venus"' 1 --anonymous
+$result5 = spacetime call testmodule2 store_initial_chunks_for_planet 3 1 --anonymous
 if ($LASTEXITCODE -eq 0) {
     Write-Host "[PASS] store_initial_chunks_for_planet test PASSED" -ForegroundColor Green
 } else {
@@ -136,7 +136,7 @@ if ($LASTEXITCODE -eq 0) {
 Write-Host "`n--- Test 8: Chunk Saving ---" -ForegroundColor Yellow
 
 # Test individual chunk storage
-$chunkResult1 = spacetime call testmodule2 store_terrain_chunk "test_chunk_1" "earth" 0 0 0 --anonymous
+$chunkResult1 = spacetime call testmodule2 store_terrain_chunk '{"planet_id": 1, "x": 0, "y": 0, "z": 0}' --anonymous
 if ($LASTEXITCODE -eq 0) {
     Write-Host "Individual chunk storage test [PASS]" -ForegroundColor Green
 } else {
@@ -145,7 +145,7 @@ if ($LASTEXITCODE -eq 0) {
 }
 
 # Test bulk chunk creation
-$chunkResult2 = spacetime call testmodule2 store_initial_chunks_for_planet "mars" 2 --anonymous
+$chunkResult2 = spacetime call testmodule2 store_initial_chunks_for_planet 2 2 --anonymous
 if ($LASTEXITCODE -eq 0) {
     Write-Host "Bulk chunk creation test [PASS]" -ForegroundColor Green
 } else {
@@ -154,7 +154,7 @@ if ($LASTEXITCODE -eq 0) {
 }
 
 # Test chunk retrieval
-$chunkResult3 = spacetime call testmodule2 get_terrain_chunk "test_chunk_1" --anonymous
+$chunkResult3 = spacetime call testmodule2 get_terrain_chunk '{"planet_id": 1, "x": 0, "y": 0, "z": 0}' --anonymous
 if ($LASTEXITCODE -eq 0) {
     Write-Host "Chunk retrieval test [PASS]" -ForegroundColor Green
 } else {
@@ -1,4 +1,4 @@
-use spacetimedb::{ReducerContext, Identity, Timestamp, Table};
+use spacetimedb::{ReducerContext, Identity, Timestamp, Table, SpacetimeType};
 
 #[cfg(test)]
 mod tests;
@@ -56,16 +56,63 @@ pub struct GameTickSchedule {
     pub next_tick: Timestamp,
 }
 
+// Chunk coordinates on a planet; packs into the u64 id used as terrain chunk key
+// (same layout as the multiplayer server module)
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChunkCoord {
+    pub planet_id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl ChunkCoord {
+    // Layout: planet_id (16 bits) | x (16 bits) | y (16 bits) | z (16 bits), axes as i16
+    pub fn chunk_id(&self) -> Option<u64> {
+        let planet_id = u16::try_from(self.planet_id).ok()? as u64;
+        let axis = |value: i32| i16::try_from(value).ok().map(|v| v as u16 as u64);
+        Some(planet_id << 48 | axis(self.x)? << 32 | axis(self.y)? << 16 | axis(self.z)?)
+    }
+
+    pub fn from_chunk_id(chunk_id: u64) -> ChunkCoord {
+        let axis = |shift: u32| (chunk_id >> shift) as u16 as i16 as i32;
+        ChunkCoord {
+            planet_id: (chunk_id >> 48) as u32,
+            x: axis(32),
+            y: axis(16),
+            z: axis(0),
+        }
+    }
+}
+
 // Terrain chunk table
-#[spacetimedb::table(name = terrain_chunk, public)]
+#[spacetimedb::table(
+    name = terrain_chunk,
+    public,
+    index(name = planet_chunk, btree(columns = [planet_id, x, y, z]))
+)]
 pub struct TerrainChunk {
     #[primary_key]
-    pub chunk_key: String,
-    pub planet_type: String,
+    pub chunk_id: u64,
+    pub planet_id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub created_at: Timestamp,
+}
+
+// Response to get_terrain_chunk, one row per requester
+#[spacetimedb::table(name = chunk_request, public)]
+pub struct ChunkRequest {
+    #[primary_key]
+    pub requester: Identity,
+    pub chunk_id: u64,
+    pub planet_id: u32,
     pub x: i32,
     pub y: i32,
     pub z: i32,
     pub created_at: Timestamp,
+    pub requested_at: Timestamp,
 }
 
 // Initialize the module
@@ -107,6 +154,9 @@ pub fn identity_disconnected(ctx: &ReducerContext) {
         
         log::info!("Player {} disconnected", player.username);
     }
+    
+    // Drop the client's pending chunk response
+    ctx.db.chunk_request().requester().delete(ctx.sender);
 }
 
 // Register a new player
@@ -232,62 +282,102 @@ pub fn update_player_position(
 
 // Store terrain chunk data
 #[spacetimedb::reducer]
-pub fn store_terrain_chunk(
-    ctx: &ReducerContext,
-    chunk_key: String,
-    planet_type: String,
-    x: i32,
-    y: i32,
-    z: i32
-) {
+pub fn store_terrain_chunk(ctx: &ReducerContext, coord: ChunkCoord) {
+    let Some(chunk_id) = coord.chunk_id() else {
+        log::warn!("Terrain chunk {:?} is out of range", coord);
+        return;
+    };
+    
     // Check if chunk already exists
-    if let Some(_existing) = ctx.db.terrain_chunk().chunk_key().find(&chunk_key) {
-        log::warn!("Terrain chunk {} already exists", chunk_key);
+    if let Some(_existing) = ctx.db.terrain_chunk().chunk_id().find(chunk_id) {
+        log::warn!("Terrain chunk {:?} already exists", coord);
         return;
     }
     
     ctx.db.terrain_chunk().insert(TerrainChunk {
-        chunk_key: chunk_key.clone(),
-        planet_type,
-        x,
-        y,
-        z,
+        chunk_id,
+        planet_id: coord.planet_id,
+        x: coord.x,
+        y: coord.y,
+        z: coord.z,
         created_at: ctx.timestamp,
     });
     
-    log::info!("Stored terrain chunk: {}", chunk_key);
+    log::info!("Stored terrain chunk: {:?}", coord);
 }
 
 // Get terrain chunk data
 #[spacetimedb::reducer]
-pub fn get_terrain_chunk(ctx: &ReducerContext, chunk_key: String) {
-    if let Some(chunk) = ctx.db.terrain_chunk().chunk_key().find(&chunk_key) {
-        log::info!("Retrieved terrain chunk: {} at ({}, {}, {})", 
-                  chunk.chunk_key, chunk.x, chunk.y, chunk.z);
+pub fn get_terrain_chunk(ctx: &ReducerContext, coord: ChunkCoord) {
+    let Some(chunk_id) = coord.chunk_id() else {
+        log::warn!("Terrain chunk {:?} is out of range", coord);
+        return;
+    };
+    
+    // Generate the chunk on demand if it has not been stored yet
+    let chunk = match ctx.db.terrain_chunk().chunk_id().find(chunk_id) {
+        Some(chunk) => chunk,
+        None => ctx.db.terrain_chunk().insert(TerrainChunk {
+            chunk_id,
+            planet_id: coord.planet_id,
+            x: coord.x,
+            y: coord.y,
+            z: coord.z,
+            created_at: ctx.timestamp,
+        }),
+    };
+    
+    // Hand the chunk back through the caller's chunk_request row
+    let response = ChunkRequest {
+        requester: ctx.sender,
+        chunk_id,
+        planet_id: chunk.planet_id,
+        x: chunk.x,
+        y: chunk.y,
+        z: chunk.z,
+        created_at: chunk.created_at,
+        requested_at: ctx.timestamp,
+    };
+    if ctx.db.chunk_request().requester().find(ctx.sender).is_some() {
+        ctx.db.chunk_request().requester().update(response);
     } else {
-        log::warn!("Terrain chunk not found: {}", chunk_key);
+        ctx.db.chunk_request().insert(response);
     }
+    
+    log::info!("Retrieved terrain chunk {} at ({}, {}, {}) on planet {}",
+              chunk.chunk_id, chunk.x, chunk.y, chunk.z, chunk.planet_id);
 }
 
 // Store initial chunks for a planet
 #[spacetimedb::reducer]
 pub fn store_initial_chunks_for_planet(
     ctx: &ReducerContext,
-    planet_type: String,
+    planet_id: u32,
     radius: i32
 ) {
+    // Validate the whole cube before inserting so an out-of-range radius stores nothing
+    let min_corner = ChunkCoord { planet_id, x: -radius, y: -radius, z: -radius };
+    let max_corner = ChunkCoord { planet_id, x: radius, y: radius, z: radius };
+    if min_corner.chunk_id().is_none() || max_corner.chunk_id().is_none() {
+        log::warn!("Initial chunk radius {} is out of range for planet {}", radius, planet_id);
+        return;
+    }
+    
     let mut chunks_created = 0;
     
     for x in -radius..=radius {
         for y in -radius..=radius {
             for z in -radius..=radius {
-                let chunk_key = format!("{}_{}_{}_{}", planet_type, x, y, z);
+                let coord = ChunkCoord { planet_id, x, y, z };
+                let Some(chunk_id) = coord.chunk_id() else {
+                    continue;
+                };
                 
                 // Only create if doesn't exist
-                if ctx.db.terrain_chunk().chunk_key().find(&chunk_key).is_none() {
+                if ctx.db.terrain_chunk().chunk_id().find(chunk_id).is_none() {
                     ctx.db.terrain_chunk().insert(TerrainChunk {
-                        chunk_key,
-                        planet_type: planet_type.clone(),
+                        chunk_id,
+                        planet_id,
                         x,
                         y,
                         z,
@@ -300,7 +390,7 @@ pub fn store_initial_chunks_for_planet(
         }
     }
     
-    log::info!("Created {} initial chunks for planet type: {}", chunks_created, planet_type);
+    log::info!("Created {} initial chunks for planet: {}", chunks_created, planet_id);
 }
 
 // Update player health
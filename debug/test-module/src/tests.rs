@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::*;
     
     // Note: These tests cannot run in WASM mode due to SpacetimeDB's architecture
     // They serve as documentation and would work in a native test environment
@@ -46,22 +46,54 @@ mod tests {
     #[test]
     fn test_terrain_chunk_structure() {
         // Test that TerrainChunk structure is properly defined
+        let coord = ChunkCoord { planet_id: 1, x: 0, y: 0, z: 0 };
         let chunk = TerrainChunk {
-            chunk_key: "test_0_0_0".to_string(),
-            planet_type: "earth".to_string(),
-            x: 0,
-            y: 0,
-            z: 0,
+            chunk_id: coord.chunk_id().unwrap(),
+            planet_id: coord.planet_id,
+            x: coord.x,
+            y: coord.y,
+            z: coord.z,
             created_at: Timestamp::now(),
         };
         
-        assert_eq!(chunk.chunk_key, "test_0_0_0");
-        assert_eq!(chunk.planet_type, "earth");
+        assert_eq!(chunk.chunk_id, 1 << 48);
+        assert_eq!(chunk.planet_id, 1);
         assert_eq!(chunk.x, 0);
         assert_eq!(chunk.y, 0);
         assert_eq!(chunk.z, 0);
     }
     
+    #[test]
+    fn test_chunk_coord_round_trip() {
+        // Test that chunk ids decode back to the coordinates they were packed from
+        let coord = ChunkCoord { planet_id: 7, x: -3, y: 12, z: -32768 };
+        let chunk_id = coord.chunk_id().unwrap();
+        
+        assert_eq!(ChunkCoord::from_chunk_id(chunk_id), coord);
+        assert_ne!(chunk_id, ChunkCoord { planet_id: 7, x: 12, y: -3, z: -32768 }.chunk_id().unwrap());
+        assert_eq!(ChunkCoord { planet_id: 7, x: 40000, y: 0, z: 0 }.chunk_id(), None);
+        assert_eq!(ChunkCoord { planet_id: 70000, x: 0, y: 0, z: 0 }.chunk_id(), None);
+    }
+    
+    #[test]
+    fn test_chunk_request_structure() {
+        // Test that ChunkRequest structure is properly defined
+        let coord = ChunkCoord { planet_id: 2, x: 1, y: -1, z: 3 };
+        let request = ChunkRequest {
+            requester: Identity::from_byte_array([1u8; 32]),
+            chunk_id: coord.chunk_id().unwrap(),
+            planet_id: coord.planet_id,
+            x: coord.x,
+            y: coord.y,
+            z: coord.z,
+            created_at: Timestamp::now(),
+            requested_at: Timestamp::now(),
+        };
+        
+        assert_eq!(ChunkCoord::from_chunk_id(request.chunk_id), coord);
+        assert_eq!(request.requester, Identity::from_byte_array([1u8; 32]));
+    }
+    
     #[test]
     fn test_logged_out_player_structure() {
         // Test that LoggedOutPlayerData structure is properly defined